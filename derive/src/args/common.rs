@@ -3,22 +3,28 @@ pub use clippy_error::impl_suppress_tupple_clippy_error;
 pub use fields::*;
 pub use generics::*;
 pub use interfaces::*;
+use proc_macro2::Span;
 use proc_macro2::TokenStream;
+use quote::ToTokens;
 use quote::quote;
+use quote::quote_spanned;
 
 use crate::args::common;
 use crate::utils::common::CommonArg;
 use crate::utils::common::CommonField;
+use crate::utils::common::CommonInterfaceAttrs;
 use crate::utils::common::CommonObject;
 use crate::utils::common::GetArgs;
 use crate::utils::common::GetFields;
 use crate::utils::crate_name::get_crate_name;
 use crate::utils::error::IntoTokenStream;
 use crate::utils::impl_block::BaseFnArg;
+use crate::utils::path_attr::PathAttr;
 use crate::utils::rename_rule::calc_enum_item_name;
 use crate::utils::rename_rule::calc_input_field_name;
 use crate::utils::rename_rule::calc_type_name;
 use crate::utils::type_utils::get_owned_type;
+use crate::utils::type_utils::replace_impl_list_with_vec;
 
 mod args;
 mod clippy_error;
@@ -73,7 +79,7 @@ impl ArgImplementor for () {
     }
 }
 
-pub fn impl_object(obj: &impl CommonObject) -> darling::Result<TokenStream> {
+pub fn impl_object(obj: &impl CommonInterfaceAttrs) -> darling::Result<TokenStream> {
     let object_ident = obj.get_ident();
     let name = get_type_name(obj)?;
     let crate_name = get_crate_name();
@@ -87,31 +93,129 @@ pub fn impl_object(obj: &impl CommonObject) -> darling::Result<TokenStream> {
         }
     });
 
+    let marked = (!obj.get_marks().is_empty()).then_some(quote! {
+        const MARKED: bool = true;
+    });
+
     Ok(quote! {
         impl #impl_generics #crate_name::internal::ParentType for #object_ident #ty_generics #where_clause {
             type Type = #object_ident #ty_generics;
         }
         #type_name
         impl #impl_generics #crate_name::internal::OutputTypeName for #object_ident #ty_generics #where_clause {}
-        impl #impl_generics #crate_name::internal::Object for #object_ident #ty_generics #where_clause {}
+        impl #impl_generics #crate_name::internal::Object for #object_ident #ty_generics #where_clause {
+            #marked
+        }
     })
 }
 
-pub fn impl_input_object(obj: &impl CommonObject) -> darling::Result<TokenStream> {
+/// with `input_name`, the type is also an object, it implements `TypeName` as the object
+/// and only overrides the input type name
+pub fn impl_input_object(
+    obj: &impl CommonObject,
+    input_name: Option<&str>,
+) -> darling::Result<TokenStream> {
     let object_ident = obj.get_ident();
-    let name = get_type_name(obj)?;
     let crate_name = get_crate_name();
-    let type_name = obj.should_impl_type_name().then_some(quote! {
-        impl #crate_name::internal::TypeName for #object_ident {
-            fn get_type_name() -> std::borrow::Cow<'static, str> {
-                #name.into()
-            }
+    let generics = obj.get_generics()?;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let (type_name, input_type_name) = match input_name {
+        Some(input_name) => {
+            let input_type_name = impl_input_type_name(
+                obj,
+                input_name,
+                quote!(InputTypeName),
+                quote!(get_input_type_name),
+            )?;
+            (None, input_type_name)
         }
-    });
+        None => {
+            let name = get_type_name(obj)?;
+            let type_name = obj
+                .should_impl_type_name()
+                .then(|| impl_input_type_name(obj, &name, quote!(TypeName), quote!(get_type_name)))
+                .transpose()?;
+            let input_type_name = quote! {
+                impl #impl_generics #crate_name::internal::InputTypeName for #object_ident #ty_generics #where_clause {}
+            };
+            (type_name, input_type_name)
+        }
+    };
     Ok(quote! {
         #type_name
-        impl #crate_name::internal::InputTypeName for #object_ident {}
-        impl #crate_name::internal::InputObject for #object_ident {}
+        #input_type_name
+        impl #impl_generics #crate_name::internal::InputObject for #object_ident #ty_generics #where_clause {}
+    })
+}
+
+/// the names of generic input types start with the names of their type parameters,
+/// e.g. `StringFilter` for `Filter<String>`
+fn impl_input_type_name(
+    obj: &impl CommonObject,
+    name: &str,
+    name_trait: TokenStream,
+    name_fn: TokenStream,
+) -> darling::Result<TokenStream> {
+    let object_ident = obj.get_ident();
+    let crate_name = get_crate_name();
+    let generics = obj.get_generics()?;
+    let type_params: Vec<_> = generics.type_params().map(|param| &param.ident).collect();
+    if type_params.is_empty() {
+        return Ok(quote! {
+            impl #crate_name::internal::#name_trait for #object_ident {
+                fn #name_fn() -> std::borrow::Cow<'static, str> {
+                    #name.into()
+                }
+            }
+        });
+    }
+    let mut name_generics = generics.clone();
+    let where_clause = name_generics.make_where_clause();
+    for param in &type_params {
+        where_clause
+            .predicates
+            .push(syn::parse_quote!(#param: #crate_name::internal::InputTypeName));
+    }
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    let (_, _, name_where_clause) = name_generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #crate_name::internal::#name_trait for #object_ident #ty_generics #name_where_clause {
+            fn #name_fn() -> std::borrow::Cow<'static, str> {
+                let mut name = String::new();
+                #( name.push_str(&<#type_params as #crate_name::internal::InputTypeName>::get_input_type_name()); )*
+                name.push_str(#name);
+                name.into()
+            }
+        }
+    })
+}
+
+/// the type parameters of generic input types should also implement `ToInputValue`
+pub fn impl_to_input_value(
+    obj: &impl CommonObject,
+    body: TokenStream,
+) -> darling::Result<TokenStream> {
+    let object_ident = obj.get_ident();
+    let crate_name = get_crate_name();
+    let generics = obj.get_generics()?;
+    let mut value_generics = generics.clone();
+    let type_params: Vec<_> = generics.type_params().map(|param| &param.ident).collect();
+    if !type_params.is_empty() {
+        let where_clause = value_generics.make_where_clause();
+        for param in &type_params {
+            where_clause
+                .predicates
+                .push(syn::parse_quote!(#param: #crate_name::internal::ToInputValue));
+        }
+    }
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    let (_, _, where_clause) = value_generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #crate_name::internal::ToInputValue for #object_ident #ty_generics #where_clause {
+            fn to_input_value(&self) -> #crate_name::Value {
+                #body
+            }
+        }
     })
 }
 
@@ -279,24 +383,124 @@ where
     Ok(object
         .get_fields()?
         .iter()
-        .filter(|field| !field.get_skip())
+        .filter(|field| !field.get_skip() && !field.get_flatten())
         .map(|field| common::build_field(field).into_token_stream())
         .collect())
 }
 
+/// record the shape of a type, see `Registry::add_signature`
+pub fn add_signature_code(type_name: TokenStream, signature: TokenStream) -> TokenStream {
+    let crate_name = get_crate_name();
+    quote! {
+        let signature = #crate_name::internal::TypeSignature::new(#type_name);
+        #signature
+        let registry = registry.add_signature(signature);
+    }
+}
+
+/// add the fields of the object `ty` defined by another macro, only if the object is marked
+/// with `#[graphql(mark(Trait))]`
+pub fn add_marked_signature_code(ty: TokenStream, signature: TokenStream) -> TokenStream {
+    let crate_name = get_crate_name();
+    quote! {
+        let registry = if <#ty as #crate_name::internal::Object>::MARKED {
+            let signature = #crate_name::internal::TypeSignature::new(<#ty as #crate_name::internal::Object>::get_object_type_name());
+            #signature
+            registry.add_signature(signature)
+        } else {
+            registry
+        };
+    }
+}
+
+/// reject at compile time the names defined more than once by the fields of the type and its
+/// flattened types, using the `FIELD_NAMES` of `flatten_trait`
+pub fn get_check_flattened_names_code(
+    ident: &syn::Ident,
+    names: &[(String, Span)],
+    flattened: &[(&syn::Type, Span)],
+    flatten_trait: TokenStream,
+) -> TokenStream {
+    let mut checks = Vec::new();
+    for (index, (ty, span)) in flattened.iter().enumerate() {
+        let ty_name = ty.to_token_stream().to_string();
+        for (name, name_span) in names {
+            let message = format!(
+                "Duplicate field \"{}\" in `{}`, also defined by the flattened `{}`",
+                name, ident, ty_name
+            );
+            checks.push(quote_spanned! {*name_span=>
+                const {
+                    if <#ty as #flatten_trait>::FIELD_NAMES.contains(#name) {
+                        panic!(#message);
+                    }
+                }
+            });
+        }
+        for (other, _) in &flattened[..index] {
+            let message = format!(
+                "Duplicate fields in `{}`, defined by the flattened `{}` and `{}`",
+                ident,
+                other.to_token_stream(),
+                ty_name
+            );
+            checks.push(quote_spanned! {*span=>
+                const {
+                    if <#other as #flatten_trait>::FIELD_NAMES.intersects(&<#ty as #flatten_trait>::FIELD_NAMES) {
+                        panic!(#message);
+                    }
+                }
+            });
+        }
+    }
+    quote!(#(#checks)*)
+}
+
+/// add the object to the unions of `#[graphql(extends_union(Union))]`
+pub fn get_extends_union_code(unions: &[PathAttr]) -> TokenStream {
+    let crate_name = get_crate_name();
+    unions
+        .iter()
+        .map(|PathAttr(union)| {
+            quote! {
+                let registry = registry.register::<#union>().update_union(
+                    <#union as #crate_name::internal::Union>::get_union_type_name().as_ref(),
+                    <Self as #crate_name::internal::Object>::get_object_type_name().as_ref(),
+                );
+            }
+        })
+        .collect()
+}
+
+pub fn get_fields_signature_code<O, F, A>(object: &O) -> darling::Result<TokenStream>
+where
+    O: GetFields<F>,
+    F: CommonField + GetArgs<A>,
+    A: CommonArg,
+{
+    Ok(object
+        .get_fields()?
+        .iter()
+        .filter(|field| !field.get_skip() && !field.get_flatten())
+        .map(|field| common::get_field_signature_code(field).into_token_stream())
+        .collect())
+}
+
 pub fn get_nested_type_register_code<O, F, A>(object: &O) -> darling::Result<TokenStream>
 where
     O: GetFields<F>,
     F: CommonField + GetArgs<A>,
     A: CommonArg,
 {
+    let crate_name = get_crate_name();
     let mut errors = Vec::new();
     let mut types = Vec::new();
+    let mut with_outputs = Vec::new();
 
     let fields = object.get_fields()?;
     fields
         .iter()
-        .filter(|field| !field.get_skip())
+        .filter(|field| !field.get_skip() && !field.get_flatten())
         .for_each(|field| {
             let args = match field.get_args() {
                 Ok(args) => args,
@@ -315,6 +519,12 @@ where
                     }
                 }
             });
+            if let Some(with) = field.get_with() {
+                with_outputs.push(quote! {
+                    let registry = #crate_name::internal::register_with_output(registry, #with);
+                });
+                return;
+            }
             let ty = field.get_type();
             match ty {
                 Ok(ty) => {
@@ -326,22 +536,43 @@ where
             };
         });
 
+    let errors_unions = fields
+        .iter()
+        .filter(|field| !field.get_skip())
+        // errors are reported when defining the field
+        .filter_map(|field| get_errors_as_union_types(field).ok().flatten())
+        .map(|errors_as_union| {
+            let name = errors_as_union.name;
+            let ok_type = errors_as_union.ok_type;
+            let err_type = errors_as_union.err_type;
+            quote! {
+                let registry = registry.register_errors_union::<#ok_type, #err_type>(#name);
+            }
+        })
+        .collect::<Vec<_>>();
+
     let errors = errors
         .into_iter()
         .map(|err| err.write_errors())
         .collect::<Vec<_>>();
-    let codes = types
-        .into_iter()
+    let codes = get_types_register_code(&types);
+
+    Ok(quote! {
+        #(#errors)*
+        #codes
+        #(#with_outputs)*
+        #(#errors_unions)*
+    })
+}
+
+pub fn get_types_register_code(types: &[&syn::Type]) -> TokenStream {
+    types
+        .iter()
         .map(|ty| {
-            let ty = replace_type_generics_with_static(ty);
+            let ty = replace_type_generics_with_static(&replace_impl_list_with_vec(ty));
             quote! {
                 let registry = registry.register::<#ty>();
             }
         })
-        .collect::<Vec<_>>();
-
-    Ok(quote! {
-        #(#errors)*
-        #(#codes)*
-    })
+        .collect()
 }
@@ -1,6 +1,8 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
+use crate::args::common::input_default_value_code;
+use crate::args::common::input_deprecation_code;
 use crate::utils::common::CommonArg;
 use crate::utils::crate_name::get_crate_name;
 use crate::utils::impl_block::BaseFnArg;
@@ -37,7 +39,10 @@ pub fn get_typed_arg_usage(arg: &impl CommonArg) -> darling::Result<TokenStream>
     }
 }
 
-pub fn get_argument_definition(arg: &impl CommonArg) -> darling::Result<TokenStream> {
+pub fn get_argument_definition(
+    arg: &impl CommonArg,
+    field_name: &str,
+) -> darling::Result<TokenStream> {
     if is_arg_ctx(arg) {
         return Ok(quote!());
     }
@@ -58,16 +63,53 @@ pub fn get_argument_definition(arg: &impl CommonArg) -> darling::Result<TokenStr
         },
         _ => quote!(),
     };
+    let input = format!("Argument \"{}\" of field \"{}\"", arg_name, field_name);
+    let default_value = arg.get_default_value()?;
+    let default = input_default_value_code(&default_value, &typed.ty, quote!(arg));
+    let deprecation = input_deprecation_code(
+        &arg.get_deprecation()?,
+        &default_value,
+        &typed.ty,
+        quote!(arg),
+        &input,
+    );
 
     Ok(quote! {
         let arg = #crate_name::dynamic::InputValue::new(#arg_name, <#arg_type as #crate_name::internal::GetInputTypeRef>::get_input_type_ref());
         #description
+        #default
+        #deprecation
         let field = field.argument(arg);
     })
 }
 
-pub fn get_argument_definitions(args: &[impl CommonArg]) -> darling::Result<TokenStream> {
-    args.iter().map(get_argument_definition).collect()
+/// record the shape of the argument, see `FieldSignature::argument`
+pub fn get_argument_signature(arg: &impl CommonArg) -> darling::Result<TokenStream> {
+    if is_arg_ctx(arg) {
+        return Ok(quote!());
+    }
+    let BaseFnArg::Typed(typed) = arg.get_arg() else {
+        return Ok(quote!());
+    };
+    let crate_name = get_crate_name();
+    let arg_name = calc_arg_name(
+        arg.get_name(),
+        &typed.ident.to_string(),
+        arg.get_arg_rename_rule(),
+    );
+    let arg_type = get_owned_type(&typed.ty);
+    Ok(quote! {
+        .argument(#arg_name, <#arg_type as #crate_name::internal::GetInputTypeRef>::get_input_type_ref())
+    })
+}
+
+pub fn get_argument_definitions(
+    args: &[impl CommonArg],
+    field_name: &str,
+) -> darling::Result<TokenStream> {
+    args.iter()
+        .map(|arg| get_argument_definition(arg, field_name))
+        .collect()
 }
 
 pub fn get_typed_arg_definition(arg: &impl CommonArg) -> darling::Result<TokenStream> {
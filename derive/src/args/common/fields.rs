@@ -1,20 +1,61 @@
 use proc_macro2::TokenStream;
 use quote::quote;
+use quote::quote_spanned;
+use syn::spanned::Spanned;
 
 use crate::args::common::ArgImplementor;
 use crate::args::common::FieldImplementor;
+use crate::args::common::get_argument_signature;
+use crate::args::common::replace_type_generics_with_static;
+use crate::utils::common::CommonArg;
 use crate::utils::common::CommonField;
 use crate::utils::common::GetArgs;
 use crate::utils::crate_name::get_crate_name;
+use crate::utils::default_value::DefaultValue;
 use crate::utils::deprecation::Deprecation;
 use crate::utils::error::IntoTokenStream;
 use crate::utils::rename_rule::calc_field_name;
+use crate::utils::rename_rule::calc_type_name;
+use crate::utils::type_utils::get_impl_list_conversion;
 use crate::utils::type_utils::get_owned_type;
+use crate::utils::type_utils::get_result_types;
+use crate::utils::type_utils::replace_impl_list_with_vec;
 
-pub fn get_field_type(field: &impl CommonField) -> darling::Result<&syn::Type> {
+pub fn get_field_type(field: &impl CommonField) -> darling::Result<syn::Type> {
     let ty = field.get_type()?;
     let owned_type = get_owned_type(ty);
-    Ok(owned_type)
+    Ok(replace_impl_list_with_vec(owned_type))
+}
+
+pub struct ErrorsAsUnionTypes {
+    pub name: String,
+    pub ok_type: syn::Type,
+    pub err_type: syn::Type,
+}
+
+/// get the union name, the object type and the error type of an `#[graphql(errors_as_union)]` field
+pub fn get_errors_as_union_types(
+    field: &impl CommonField,
+) -> darling::Result<Option<ErrorsAsUnionTypes>> {
+    let Some(errors_as_union) = field.get_errors_as_union() else {
+        return Ok(None);
+    };
+    let ty = field.get_type()?;
+    let Some((ok_type, err_type)) = get_result_types(ty) else {
+        return Err(darling::Error::custom(
+            "errors_as_union requires a `Result<T, E>` return type",
+        )
+        .with_span(ty));
+    };
+    let name = match &errors_as_union.name {
+        Some(name) => name.clone(),
+        None => calc_type_name(None, &format!("{}Result", get_field_name(field)?)),
+    };
+    Ok(Some(ErrorsAsUnionTypes {
+        name,
+        ok_type: replace_type_generics_with_static(get_owned_type(ok_type)),
+        err_type: replace_type_generics_with_static(get_owned_type(err_type)),
+    }))
 }
 
 pub fn get_args_definition<F, A>(field: &F) -> darling::Result<TokenStream>
@@ -57,6 +98,59 @@ pub fn field_deprecation_code(deprecation: &impl CommonField) -> darling::Result
     }
 }
 
+/// deprecate the input value `var`, `input` describes it in the compile error of required inputs
+///
+/// required inputs are non-null and have no default value, they can't be deprecated
+pub fn input_deprecation_code(
+    deprecation: &Deprecation,
+    default_value: &DefaultValue,
+    ty: &syn::Type,
+    var: TokenStream,
+    input: &str,
+) -> TokenStream {
+    let crate_name = get_crate_name();
+    let reason = match deprecation {
+        Deprecation::NoDeprecated => return quote!(),
+        Deprecation::Deprecated { reason: None } => quote!(None),
+        Deprecation::Deprecated {
+            reason: Some(reason),
+        } => quote!(Some(#reason)),
+    };
+    let check_nullable = if default_value.is_set() {
+        quote!()
+    } else {
+        let ty = get_owned_type(ty);
+        let message = format!("{} is required and can't be deprecated", input);
+        quote_spanned! {ty.span()=>
+            const {
+                if !<#ty as #crate_name::internal::GetInputTypeRef>::NULLABLE {
+                    panic!(#message);
+                }
+            }
+        }
+    };
+    quote! {
+        #check_nullable
+        let #var = #var.deprecation(#reason);
+    }
+}
+
+/// set the default value of the input value `var`
+pub fn input_default_value_code(
+    default_value: &DefaultValue,
+    ty: &syn::Type,
+    var: TokenStream,
+) -> TokenStream {
+    let crate_name = get_crate_name();
+    let ty = get_owned_type(ty);
+    match default_value.get_value_code(ty) {
+        Some(value) => quote! {
+            let #var = #var.default_value(#crate_name::internal::ToInputValue::to_input_value(&#value));
+        },
+        None => quote!(),
+    }
+}
+
 pub fn field_description(field: &impl CommonField) -> darling::Result<TokenStream> {
     let doc = field.get_doc()?;
     // todo get "field" from input
@@ -77,6 +171,48 @@ pub fn get_field_name(field: &impl CommonField) -> darling::Result<String> {
     ))
 }
 
+pub fn get_field_type_ref_code(field: &impl CommonField) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    if let Some(errors_as_union) = get_errors_as_union_types(field)? {
+        let union_name = errors_as_union.name;
+        return Ok(quote!(#crate_name::dynamic::TypeRef::named_nn(#union_name)));
+    }
+    let type_ref = match field.get_with() {
+        Some(with) => quote!(#crate_name::internal::get_with_output_type_ref(#with)),
+        None => {
+            let field_type = get_field_type(field)?;
+            quote!(<#field_type as #crate_name::internal::GetOutputTypeRef>::get_output_type_ref())
+        }
+    };
+    if field.get_partial_list() {
+        // failed items resolve to `null`, so the items are nullable
+        return Ok(quote!(#type_ref.nullable_items()));
+    }
+    Ok(type_ref)
+}
+
+/// record the shape of the field, see `Registry::add_signature`
+pub fn get_field_signature_code<F, A>(field: &F) -> darling::Result<TokenStream>
+where
+    F: CommonField + GetArgs<A>,
+    A: CommonArg,
+{
+    let crate_name = get_crate_name();
+    let field_name = get_field_name(field)?;
+    let field_type_ref = get_field_type_ref_code(field)?;
+    let arguments = field
+        .get_args()?
+        .iter()
+        .map(get_argument_signature)
+        .collect::<darling::Result<Vec<_>>>()?;
+    Ok(quote! {
+        let signature = signature.field(
+            #crate_name::internal::FieldSignature::new(#field_name, #field_type_ref)
+            #(#arguments)*
+        );
+    })
+}
+
 pub fn define_field<F, A>(method: &F) -> darling::Result<TokenStream>
 where
     F: FieldImplementor + GetArgs<A>,
@@ -85,12 +221,12 @@ where
     let crate_name = get_crate_name();
 
     let field_name = get_field_name(method)?;
-    let field_type = get_field_type(method)?;
     let graphql_args_definition = get_args_definition(method)?;
     let execute = method.get_execute_code()?;
     let resolve = method.get_resolve_code()?;
+    let field_type_ref = get_field_type_ref_code(method)?;
     Ok(quote! {
-        let field = #crate_name::dynamic::Field::new(#field_name, <#field_type as #crate_name::internal::GetOutputTypeRef>::get_output_type_ref(), |ctx| {
+        let field = #crate_name::dynamic::Field::new(#field_name, #field_type_ref, move |ctx| {
             #crate_name::dynamic::FieldFuture::new(async move {
                 #graphql_args_definition
                 #execute
@@ -120,10 +256,53 @@ where
     })
 }
 
-pub fn resolve_value_code() -> darling::Result<TokenStream> {
+pub fn resolve_value_code(field: &impl CommonField) -> darling::Result<TokenStream> {
     let crate_name = get_crate_name();
+    let conversion = match get_impl_list_conversion(field.get_type()?) {
+        Some(conversion) => quote! {
+            let value = #conversion;
+        },
+        None => quote!(),
+    };
+
+    if let Some(errors_as_union) = get_errors_as_union_types(field)? {
+        let ok_type = errors_as_union.ok_type;
+        // the error union sets the type of its members
+        return Ok(quote! {
+            match value {
+                Ok(value) => #crate_name::internal::Resolve::resolve(value, &ctx).map(|value| value.map(|value| value.with_type(<#ok_type as #crate_name::internal::Object>::get_object_type_name()))),
+                Err(err) => #crate_name::internal::Resolve::resolve(err, &ctx),
+            }
+        });
+    }
+
+    if field.get_partial_list() {
+        return Ok(quote! {
+            #conversion
+            #crate_name::internal::ResolvePartialList::resolve_partial_list(value, &ctx)
+        });
+    }
 
     Ok(quote! {
+        #conversion
         #crate_name::internal::Resolve::resolve(value, &ctx)
     })
 }
+
+/// Like [`resolve_value_code`] for the owned values returned by the methods, the errors of a
+/// `Result` keep the extensions of a `GraphQLError`
+pub fn resolve_method_value_code(field: &impl CommonField) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let resolve = resolve_value_code(field)?;
+    if field.get_errors_as_union().is_some() || get_result_types(field.get_type()?).is_none() {
+        return Ok(resolve);
+    }
+    Ok(quote! {
+        let value = value.map_err(|err| {
+            use #crate_name::internal::ExtensionsResolverErrorKind as _;
+            use #crate_name::internal::IntoResolverErrorKind as _;
+            (&err).resolver_error_kind().into_resolver_error(err)
+        });
+        #resolve
+    })
+}
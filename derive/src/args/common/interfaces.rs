@@ -1,5 +1,7 @@
 use proc_macro2::TokenStream;
 use quote::quote;
+use quote::quote_spanned;
+use syn::spanned::Spanned;
 
 use crate::utils::common::CommonInterfaceAttrs;
 use crate::utils::common::CommonObject;
@@ -30,6 +32,97 @@ pub fn get_register_interface_code(
     })
 }
 
+/// split `Trait::field` of `interface_default(...)` to the marked interface and the method name
+fn get_interface_default_target(
+    obj: &impl CommonInterfaceAttrs,
+    path: &syn::Path,
+) -> darling::Result<(syn::Path, String)> {
+    let marks = obj.get_marks();
+    let Some(method) = path.segments.last() else {
+        return Err(darling::Error::custom("Expected a field name").with_span(path));
+    };
+    let method = method.ident.to_string();
+    if path.segments.len() == 1 {
+        return match marks.as_slice() {
+            [mark] => Ok((mark.path.clone(), method)),
+            [] => Err(darling::Error::custom(
+                "interface_default requires a `mark(Trait)` attribute",
+            )
+            .with_span(path)),
+            _ => Err(darling::Error::custom(format!(
+                "object has more than one marked interface, use `interface_default(Trait::{})`",
+                method
+            ))
+            .with_span(path)),
+        };
+    }
+    let mut interface = path.clone();
+    interface.segments.pop();
+    interface.segments.pop_punct();
+    let interface_name = path_to_string(&interface);
+    if !marks
+        .iter()
+        .any(|mark| path_to_string(&mark.path) == interface_name)
+    {
+        return Err(darling::Error::custom(format!(
+            "`{}` is not marked with `mark({})`",
+            interface_name, interface_name
+        ))
+        .with_span(path));
+    }
+    Ok((interface, method))
+}
+
+pub fn get_interface_default_code(obj: &impl CommonInterfaceAttrs) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let mut errors = darling::Error::accumulator();
+    let mut defaults: Vec<(syn::Path, Vec<String>)> = Vec::new();
+    let mut check_methods = Vec::new();
+    for path in obj
+        .get_interface_defaults()
+        .iter()
+        .flat_map(|attr| attr.paths.iter())
+    {
+        let Some((interface, method)) = errors.handle(get_interface_default_target(obj, path))
+        else {
+            continue;
+        };
+        let message = format!(
+            "`{}` is not a method of `{}`",
+            method,
+            path_to_string(&interface)
+        );
+        check_methods.push(quote_spanned! {path.span()=>
+            const {
+                if !<dyn #interface as #crate_name::internal::RegisterInstance<dyn #interface, Self>>::METHOD_NAMES.contains(#method) {
+                    panic!(#message);
+                }
+            }
+        });
+        match defaults
+            .iter_mut()
+            .find(|(path, _)| path_to_string(path) == path_to_string(&interface))
+        {
+            Some((_, methods)) => methods.push(method),
+            None => defaults.push((interface, vec![method])),
+        }
+    }
+    errors.finish()?;
+
+    let codes: Vec<_> = defaults
+        .iter()
+        .map(|(path, methods)| {
+            quote! {
+                let registry = <dyn #path as #crate_name::internal::RegisterInstance<dyn #path, Self>>::register_default_fields(registry, &[#(#methods),*]);
+            }
+        })
+        .collect();
+    Ok(quote! {
+        #(#check_methods)*
+        #(#codes)*
+    })
+}
+
 pub fn get_interface_mark_code(obj: &impl CommonInterfaceAttrs) -> darling::Result<TokenStream> {
     let crate_name = get_crate_name();
     let implements: Vec<TokenStream> = obj
@@ -47,6 +140,34 @@ pub fn get_interface_mark_code(obj: &impl CommonInterfaceAttrs) -> darling::Resu
     })
 }
 
+pub fn get_interface_mark_signature_code(
+    obj: &impl CommonInterfaceAttrs,
+) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let marks: Vec<TokenStream> = obj
+        .get_marks()
+        .iter()
+        .map(|interface| {
+            let path = &interface.path;
+            let rust_trait = path_to_string(path);
+            quote! {
+                let signature = signature.mark(<dyn #path as #crate_name::internal::Interface>::get_interface_type_name(), #rust_trait);
+            }
+        })
+        .collect();
+    Ok(quote! {
+        #(#marks)*
+    })
+}
+
+fn path_to_string(path: &syn::Path) -> String {
+    path.segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
 pub fn get_add_implement_code(
     object: &impl CommonObject,
     implement: &[InterfaceImplAttr],
@@ -0,0 +1,226 @@
+use darling::FromAttributes;
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use quote::quote;
+use syn::Generics;
+use syn::Path;
+
+use crate::args::common;
+use crate::args::common::get_enum_item_name;
+use crate::args::gql_enum::EnumVariant;
+use crate::args::gql_enum::EnumVariantContext;
+use crate::args::gql_enum::check_variants;
+use crate::args::gql_enum::get_from_value_match_items;
+use crate::args::gql_enum::impl_into_value;
+use crate::args::gql_enum::impl_to_input_value;
+use crate::args::gql_enum::register_items;
+use crate::utils::common::CommonField;
+use crate::utils::common::CommonObject;
+use crate::utils::common::GetFields;
+use crate::utils::crate_name::get_crate_name;
+use crate::utils::derive_types::BaseEnum;
+use crate::utils::error::IntoTokenStream;
+use crate::utils::macros::*;
+use crate::utils::path_attr::PathAttr;
+use crate::utils::register_attr::RegisterAttr;
+use crate::utils::rename_rule::RenameRule;
+use crate::utils::with_attributes::WithAttributes;
+use crate::utils::with_context::MakeContext;
+
+#[derive(FromAttributes, Debug, Clone)]
+#[darling(attributes(graphql))]
+pub struct EnumExtensionAttributes {
+    pub extends: PathAttr,
+
+    #[darling(default)]
+    pub rename_items: Option<RenameRule>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "register")]
+    pub registers: Vec<RegisterAttr>,
+}
+
+from_derive_input!(
+    EnumExtension,
+    WithAttributes<EnumExtensionAttributes, BaseEnum<EnumVariant, Generics>>,
+    ctx,
+);
+
+impl MakeContext<EnumVariantContext> for EnumExtension {
+    fn make_context(&self) -> EnumVariantContext {
+        EnumVariantContext {
+            rename_items: self.attrs.rename_items,
+        }
+    }
+}
+
+impl CommonObject for EnumExtension {
+    fn get_name(&self) -> Option<&str> {
+        None
+    }
+
+    fn should_impl_type_name(&self) -> bool {
+        false
+    }
+
+    fn get_ident(&self) -> &syn::Ident {
+        &self.ident
+    }
+
+    fn get_type(&self) -> darling::Result<Path> {
+        Ok(self.ident.clone().into())
+    }
+
+    fn get_generics(&self) -> darling::Result<&Generics> {
+        Ok(&self.generics)
+    }
+
+    fn get_doc(&self) -> darling::Result<Option<String>> {
+        Ok(None)
+    }
+}
+
+impl GetFields<EnumVariant> for EnumExtension {
+    fn get_fields(&self) -> darling::Result<&Vec<EnumVariant>> {
+        Ok(&self.data)
+    }
+}
+
+/// the values of the extension are added to a single enum, so there is no generic version
+fn check_generics(enm: &EnumExtension) -> darling::Result<()> {
+    match enm.generics.params.first() {
+        Some(param) => Err(
+            darling::Error::custom("generics are not supported by EnumExtension").with_span(param),
+        ),
+        None => Ok(()),
+    }
+}
+
+fn impl_enum(enm: &EnumExtension) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let enum_ident = enm.get_ident();
+    let tag = &enm.attrs.extends.0;
+
+    Ok(quote! {
+        impl #crate_name::internal::TypeName for #enum_ident {
+            fn get_type_name() -> std::borrow::Cow<'static, str> {
+                <#tag as #crate_name::DynEnumTag>::get_enum_name()
+            }
+        }
+        impl #crate_name::internal::InputTypeName for #enum_ident {}
+        impl #crate_name::internal::OutputTypeName for #enum_ident {}
+        impl #crate_name::internal::Enum for #enum_ident {}
+    })
+}
+
+fn impl_from_value(enm: &EnumExtension) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let enum_ident = enm.get_ident();
+    let match_items = get_from_value_match_items(enm)?;
+
+    Ok(quote! {
+        impl #crate_name::internal::FromValue for #enum_ident {
+            fn from_value(__value: #crate_name::Result<#crate_name::dynamic::ValueAccessor>) -> #crate_name::internal::InputValueResult<Self> {
+                let __value = __value?;
+                let string_value = __value.enum_name()?;
+                match string_value {
+                    #match_items
+                    _ => Err(#crate_name::internal::InputValueError::custom(
+                        format!("Unknown variant `{}` for enum `{}`", string_value, <#enum_ident as #crate_name::internal::Enum>::get_enum_type_name().as_ref()),
+                    )),
+                }
+            }
+        }
+    })
+}
+
+fn impl_dyn_enum_conversions(enm: &EnumExtension) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let enum_ident = enm.get_ident();
+    let tag = &enm.attrs.extends.0;
+
+    let mut into_items = Vec::new();
+    let mut from_items = Vec::new();
+    for variant in &enm.data {
+        let variant_ident = variant.get_ident()?;
+        let variant_name = get_enum_item_name(variant)?;
+        into_items.push(quote!(#enum_ident::#variant_ident => #variant_name,));
+        from_items.push(quote!(#variant_name => Ok(#enum_ident::#variant_ident),));
+    }
+
+    Ok(quote! {
+        impl From<#enum_ident> for #crate_name::DynEnum<#tag> {
+            fn from(value: #enum_ident) -> Self {
+                #crate_name::DynEnum::new(match value {
+                    #(#into_items)*
+                })
+            }
+        }
+        impl TryFrom<#crate_name::DynEnum<#tag>> for #enum_ident {
+            type Error = #crate_name::DynEnum<#tag>;
+
+            fn try_from(value: #crate_name::DynEnum<#tag>) -> Result<Self, Self::Error> {
+                match value.as_str() {
+                    #(#from_items)*
+                    _ => Err(value),
+                }
+            }
+        }
+    })
+}
+
+fn impl_register(enm: &EnumExtension) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let enum_ident = enm.get_ident();
+    let tag = &enm.attrs.extends.0;
+    let items = register_items(enm)?;
+    let register_attr = &enm.attrs.registers;
+    let expansion_name = enum_ident.to_string();
+
+    Ok(quote! {
+        impl #crate_name::internal::Register for #enum_ident {
+            fn register(registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry {
+                #( #register_attr )*
+                let registry = registry.register::<#crate_name::DynEnum<#tag>>();
+                registry.update_enum(
+                    <#tag as #crate_name::DynEnumTag>::get_enum_name().as_ref(),
+                    #expansion_name,
+                    |object| {
+                        #items
+                        object
+                    },
+                )
+            }
+        }
+    })
+}
+
+impl ToTokens for EnumExtension {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let mut errors = darling::Error::accumulator();
+        errors.handle(check_generics(self));
+        errors.handle(check_variants(&self.data, false));
+        if let Err(err) = errors.finish() {
+            tokens.extend(err.write_errors());
+            return;
+        }
+        let impl_enum = impl_enum(self).into_token_stream();
+        let impl_into_value = impl_into_value(self).into_token_stream();
+        let impl_resolve_owned = common::impl_resolve_owned_by_value(self).into_token_stream();
+        let impl_resolve_ref = common::impl_resolve_ref_by_value(self).into_token_stream();
+        let impl_from_value = impl_from_value(self).into_token_stream();
+        let impl_to_input_value = impl_to_input_value(self).into_token_stream();
+        let impl_conversions = impl_dyn_enum_conversions(self).into_token_stream();
+        let impl_register = impl_register(self).into_token_stream();
+        tokens.extend(quote! {
+            #impl_enum
+            #impl_into_value
+            #impl_resolve_owned
+            #impl_resolve_ref
+            #impl_from_value
+            #impl_to_input_value
+            #impl_conversions
+            #impl_register
+        });
+    }
+}
@@ -16,8 +16,10 @@ use crate::utils::common::CommonObject;
 use crate::utils::common::GetArgs;
 use crate::utils::common::GetFields;
 use crate::utils::crate_name::get_crate_name;
+use crate::utils::default_value::DefaultValue;
 use crate::utils::deprecation::Deprecation;
 use crate::utils::error::IntoTokenStream;
+use crate::utils::errors_as_union::ErrorsAsUnion;
 use crate::utils::impl_block::BaseFnArg;
 use crate::utils::impl_block::BaseItemImpl;
 use crate::utils::impl_block::BaseMethod;
@@ -43,6 +45,12 @@ pub struct ExpandObjectFieldsArgAttrs {
     /// Description for this argument exposed in the GraphQL schema. Takes
     #[darling(default)]
     pub desc: Option<String>,
+
+    #[darling(default)]
+    pub deprecation: Deprecation,
+
+    #[darling(default)]
+    pub default: DefaultValue,
 }
 
 impl Attributes for ExpandObjectFieldsArgAttrs {
@@ -76,6 +84,12 @@ pub struct ExpandObjectFieldsMethodAttrs {
 
     #[darling(default)]
     pub deprecation: Deprecation,
+
+    #[darling(default)]
+    pub partial_list: bool,
+
+    #[darling(default)]
+    pub errors_as_union: Option<ErrorsAsUnion>,
 }
 
 impl Attributes for ExpandObjectFieldsMethodAttrs {
@@ -193,6 +207,12 @@ impl CommonField for ExpandObjectFieldsMethod {
     fn get_field_rename_rule(&self) -> Option<&RenameRule> {
         self.ctx.rename_fields.as_ref()
     }
+    fn get_partial_list(&self) -> bool {
+        self.attrs.partial_list
+    }
+    fn get_errors_as_union(&self) -> Option<&ErrorsAsUnion> {
+        self.attrs.errors_as_union.as_ref()
+    }
     fn get_args_rename_rule(&self) -> Option<&RenameRule> {
         self.attrs
             .rename_args
@@ -225,6 +245,14 @@ impl CommonArg for ExpandObjectFieldsArg {
     fn get_doc(&self) -> darling::Result<Option<String>> {
         Ok(self.attrs.desc.clone())
     }
+
+    fn get_deprecation(&self) -> darling::Result<Deprecation> {
+        Ok(self.attrs.deprecation.clone())
+    }
+
+    fn get_default_value(&self) -> darling::Result<DefaultValue> {
+        Ok(self.attrs.default.clone())
+    }
 }
 
 impl CommonMethod for ExpandObjectFieldsMethod {
@@ -281,11 +309,11 @@ impl FieldImplementor for ExpandObjectFieldsMethod {
     }
 
     fn get_resolve_code(&self) -> darling::Result<TokenStream> {
-        common::resolve_value_code()
+        common::resolve_method_value_code(self)
     }
 
     fn get_field_argument_definition(&self) -> darling::Result<TokenStream> {
-        common::get_argument_definitions(self.get_args()?)
+        common::get_argument_definitions(self.get_args()?, &common::get_field_name(self)?)
     }
 
     fn get_field_description_code(&self) -> darling::Result<TokenStream> {
@@ -356,6 +384,12 @@ fn impl_register(expand: &ExpandObjectFields) -> darling::Result<TokenStream> {
 
     let define_fields = common::get_define_fields_code(expand).into_token_stream();
 
+    let fields_signature = common::get_fields_signature_code(expand).into_token_stream();
+    let add_signature = common::add_marked_signature_code(
+        quote!(<Self as #crate_name::internal::ParentType>::Type),
+        fields_signature,
+    );
+
     let use_fields = use_fields_code(expand).into_token_stream();
 
     let register_fns = common::call_register_fns();
@@ -368,6 +402,9 @@ fn impl_register(expand: &ExpandObjectFields) -> darling::Result<TokenStream> {
                 #register_fns
 
                 #define_fields
+
+                #add_signature
+
                 registry.update_object(
                     <<Self as #crate_name::internal::ParentType>::Type as #crate_name::internal::Object>::get_object_type_name().as_ref(),
                     <Self as #crate_name::internal::ExpandObject>::get_expand_object_name().as_ref(),
@@ -16,7 +16,8 @@ use crate::utils::common::GetFields;
 use crate::utils::crate_name::get_crate_name;
 use crate::utils::deprecation::Deprecation;
 use crate::utils::derive_types::BaseEnum;
-use crate::utils::derive_types::UnitVariant;
+use crate::utils::derive_types::BaseVariant;
+use crate::utils::derive_types::TupleField;
 use crate::utils::error::IntoTokenStream;
 use crate::utils::macros::*;
 use crate::utils::path_attr::PathAttr;
@@ -35,6 +36,15 @@ pub struct EnumVariantAttributes {
 
     #[darling(default)]
     deprecation: Deprecation,
+
+    /// holds the values added to the enum with `Registry::update_enum`, only the values of the
+    /// schema are accepted, unknown values are still rejected by the validation of the input and
+    /// fail to resolve as output
+    #[darling(default)]
+    other: bool,
+
+    #[darling(default)]
+    remote: Option<PathAttr>,
 }
 
 #[derive(Default, Debug, Clone)]
@@ -44,7 +54,10 @@ pub struct EnumVariantContext {
 
 from_variant!(
     EnumVariant,
-    WithAttributes<WithDoc<EnumVariantAttributes>, WithContext<EnumVariantContext, UnitVariant>>,
+    WithAttributes<
+        WithDoc<EnumVariantAttributes>,
+        WithContext<EnumVariantContext, BaseVariant<TupleField>>,
+    >,
 );
 
 #[derive(FromAttributes, Debug, Clone)]
@@ -138,6 +151,56 @@ impl CommonField for EnumVariant {
     }
 }
 
+impl EnumVariant {
+    /// the catch-all variant, holds the values unknown to the enum
+    pub fn is_other(&self) -> bool {
+        self.attrs.other
+    }
+
+    fn get_remote_ident(&self) -> TokenStream {
+        match &self.attrs.remote {
+            Some(remote) => remote.0.to_token_stream(),
+            None => self.ident.to_token_stream(),
+        }
+    }
+}
+
+/// Checks the shape of the variants, only the `other` variant can hold a value
+pub fn check_variants(variants: &[EnumVariant], allow_other: bool) -> darling::Result<()> {
+    let mut errors = darling::Error::accumulator();
+    let mut has_other = false;
+    for variant in variants {
+        if !variant.is_other() {
+            if !variant.fields.is_empty() {
+                errors.push(
+                    darling::Error::unsupported_shape("non unit variant").with_span(&variant.ident),
+                );
+            }
+            continue;
+        }
+        if !allow_other {
+            errors.push(
+                darling::Error::custom("`other` variant is not supported here")
+                    .with_span(&variant.ident),
+            );
+        } else if has_other {
+            errors.push(
+                darling::Error::custom("only one variant can be marked as `other`")
+                    .with_span(&variant.ident),
+            );
+        } else if !variant.fields.is_tuple() || variant.fields.len() != 1 {
+            errors.push(
+                darling::Error::custom(
+                    "`other` variant should hold the value, e.g. `Other(String)`",
+                )
+                .with_span(&variant.ident),
+            );
+        }
+        has_other = true;
+    }
+    errors.finish()
+}
+
 impl GetFields<EnumVariant> for Enum {
     fn get_fields(&self) -> darling::Result<&Vec<EnumVariant>> {
         Ok(&self.data)
@@ -167,11 +230,20 @@ fn impl_enum(enm: &impl CommonObject) -> darling::Result<TokenStream> {
 
 fn impl_into_value_match_item(
     enm: &impl CommonObject,
-    variant: &impl CommonField,
+    variant: &EnumVariant,
 ) -> darling::Result<TokenStream> {
     let crate_name = get_crate_name();
     let ty = enm.get_ident();
     let variant_ident = variant.get_ident()?;
+    if variant.is_other() {
+        return Ok(quote! {
+            #ty::#variant_ident(value) => {
+                #crate_name::Value::Enum(
+                    #crate_name::Name::new(value)
+                )
+            }
+        });
+    }
     let variant_name = get_enum_item_name(variant)?;
 
     Ok(quote! {
@@ -183,10 +255,9 @@ fn impl_into_value_match_item(
     })
 }
 
-fn impl_into_value_match_items<T, F>(enm: &T) -> darling::Result<TokenStream>
+fn impl_into_value_match_items<T>(enm: &T) -> darling::Result<TokenStream>
 where
-    T: GetFields<F> + CommonObject,
-    F: CommonField,
+    T: GetFields<EnumVariant> + CommonObject,
 {
     Ok(enm
         .get_fields()?
@@ -195,10 +266,9 @@ where
         .collect())
 }
 
-fn impl_into_value<T, F>(enm: &T) -> darling::Result<TokenStream>
+pub fn impl_into_value<T>(enm: &T) -> darling::Result<TokenStream>
 where
-    T: GetFields<F> + CommonObject,
-    F: CommonField,
+    T: GetFields<EnumVariant> + CommonObject,
 {
     let crate_name = get_crate_name();
     let enum_ident = enm.get_ident();
@@ -215,9 +285,22 @@ where
     })
 }
 
+pub fn impl_to_input_value(enm: &impl CommonObject) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let enum_ident = enm.get_ident();
+
+    Ok(quote! {
+        impl #crate_name::internal::ToInputValue for #enum_ident {
+            fn to_input_value(&self) -> #crate_name::Value {
+                self.into()
+            }
+        }
+    })
+}
+
 fn get_from_value_match_item(
     enm: &impl CommonObject,
-    variant: &impl CommonField,
+    variant: &EnumVariant,
 ) -> darling::Result<TokenStream> {
     let ty = enm.get_ident();
     let variant_ident = variant.get_ident()?;
@@ -230,14 +313,14 @@ fn get_from_value_match_item(
     })
 }
 
-fn get_from_value_match_items<T, F>(enm: &T) -> darling::Result<TokenStream>
+pub fn get_from_value_match_items<T>(enm: &T) -> darling::Result<TokenStream>
 where
-    T: GetFields<F> + CommonObject,
-    F: CommonField,
+    T: GetFields<EnumVariant> + CommonObject,
 {
     Ok(enm
         .get_fields()?
         .iter()
+        .filter(|variant| !variant.is_other())
         .map(|variant| get_from_value_match_item(enm, variant).into_token_stream())
         .collect())
 }
@@ -246,6 +329,21 @@ fn impl_from_value(enm: &Enum) -> darling::Result<TokenStream> {
     let crate_name = get_crate_name();
     let enum_ident = enm.get_ident();
     let match_items = get_from_value_match_items(enm)?;
+    // the validation of the input already rejected the values missing from the schema, only the
+    // values added with `Registry::update_enum` reach the `other` variant
+    let fallback = match enm.data.iter().find(|variant| variant.is_other()) {
+        Some(other) => {
+            let other_ident = &other.ident;
+            quote! {
+                _ => Ok(#enum_ident::#other_ident(From::from(string_value))),
+            }
+        }
+        None => quote! {
+            _ => Err(#crate_name::internal::InputValueError::custom(
+                format!("Unknown variant `{}` for enum `{}`", string_value, <#enum_ident as #crate_name::internal::Enum>::get_enum_type_name().as_ref()),
+            )),
+        },
+    };
 
     Ok(quote! {
         impl #crate_name::internal::FromValue for #enum_ident {
@@ -254,9 +352,7 @@ fn impl_from_value(enm: &Enum) -> darling::Result<TokenStream> {
                 let string_value = __value.enum_name()?;
                 match string_value {
                     #match_items
-                    _ => Err(#crate_name::internal::InputValueError::custom(
-                        format!("Unknown variant `{}` for enum `{}`", string_value, <#enum_ident as #crate_name::internal::Enum>::get_enum_type_name().as_ref()),
-                    )),
+                    #fallback
                 }
             }
         }
@@ -269,8 +365,14 @@ fn impl_into_remote_item(
     item: &EnumVariant,
 ) -> darling::Result<TokenStream> {
     let item_ident = item.get_ident()?;
+    let remote_ident = item.get_remote_ident();
+    if item.is_other() {
+        return Ok(quote! {
+            #enum_ident::#item_ident(value) => #remote_path::#remote_ident(From::from(value)),
+        });
+    }
     Ok(quote! {
-            #enum_ident::#item_ident => #remote_path::#item_ident,
+            #enum_ident::#item_ident => #remote_path::#remote_ident,
     })
 }
 
@@ -298,8 +400,14 @@ fn impl_from_remote_item(
     item: &EnumVariant,
 ) -> darling::Result<TokenStream> {
     let item_ident = item.get_ident()?;
+    let remote_ident = item.get_remote_ident();
+    if item.is_other() {
+        return Ok(quote! {
+            #remote_path::#remote_ident(value) => #enum_ident::#item_ident(From::from(value)),
+        });
+    }
     Ok(quote! {
-            #remote_path::#item_ident => #enum_ident::#item_ident,
+            #remote_path::#remote_ident => #enum_ident::#item_ident,
     })
 }
 
@@ -350,14 +458,14 @@ fn register_item(variant: &impl CommonField) -> darling::Result<TokenStream> {
     })
 }
 
-fn register_items<T, F>(enm: &T) -> darling::Result<TokenStream>
+pub fn register_items<T>(enm: &T) -> darling::Result<TokenStream>
 where
-    T: GetFields<F>,
-    F: CommonField,
+    T: GetFields<EnumVariant>,
 {
     Ok(enm
         .get_fields()?
         .iter()
+        .filter(|variant| !variant.is_other())
         .map(|variant| register_item(variant).into_token_stream())
         .collect())
 }
@@ -385,11 +493,16 @@ fn impl_register(enm: &Enum) -> darling::Result<TokenStream> {
 
 impl ToTokens for Enum {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        if let Err(err) = check_variants(&self.data, true) {
+            tokens.extend(err.write_errors());
+            return;
+        }
         let impl_enum = impl_enum(self).into_token_stream();
         let impl_into_value = impl_into_value(self).into_token_stream();
         let impl_resolve_owned = common::impl_resolve_owned_by_value(self).into_token_stream();
         let impl_resolve_ref = common::impl_resolve_ref_by_value(self).into_token_stream();
         let impl_from_value = impl_from_value(self).into_token_stream();
+        let impl_to_input_value = impl_to_input_value(self).into_token_stream();
         let impl_remote = impl_remote(self).into_token_stream();
         let impl_register = impl_register(self).into_token_stream();
         tokens.extend(quote! {
@@ -398,6 +511,7 @@ impl ToTokens for Enum {
             #impl_resolve_owned
             #impl_resolve_ref
             #impl_from_value
+            #impl_to_input_value
             #impl_remote
             #impl_register
         });
@@ -0,0 +1,299 @@
+use darling::FromAttributes;
+use darling::ast::Style;
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use quote::format_ident;
+use quote::quote;
+use syn::Generics;
+
+use crate::utils::crate_name::get_crate_name;
+use crate::utils::derive_types::BaseEnum;
+use crate::utils::derive_types::BaseField;
+use crate::utils::derive_types::BaseVariant;
+use crate::utils::error::IntoTokenStream;
+use crate::utils::macros::*;
+use crate::utils::rename_rule::RenameRule;
+use crate::utils::rename_rule::calc_enum_item_name;
+use crate::utils::rename_rule::calc_field_name;
+use crate::utils::with_attributes::WithAttributes;
+use crate::utils::with_doc::WithDoc;
+
+#[derive(FromAttributes, Debug, Clone)]
+#[darling(attributes(graphql))]
+pub struct GraphQLErrorFieldAttrs {
+    #[darling(default)]
+    pub skip: bool,
+
+    #[darling(default)]
+    pub name: Option<String>,
+}
+
+from_field!(
+    GraphQLErrorField,
+    WithAttributes<GraphQLErrorFieldAttrs, BaseField>,
+);
+
+#[derive(FromAttributes, Debug, Clone)]
+#[darling(attributes(graphql))]
+pub struct GraphQLErrorVariantAttrs {
+    #[darling(default)]
+    pub code: Option<String>,
+
+    /// the message of the variant, `Display` of the error by default
+    #[darling(default)]
+    pub message: Option<String>,
+}
+
+from_variant!(
+    GraphQLErrorVariant,
+    WithAttributes<WithDoc<GraphQLErrorVariantAttrs>, BaseVariant<GraphQLErrorField>>,
+);
+
+#[derive(FromAttributes, Debug, Clone)]
+#[darling(attributes(graphql))]
+pub struct GraphQLErrorAttrs {
+    #[darling(default)]
+    pub name: Option<String>,
+
+    #[darling(default)]
+    pub rename_fields: Option<RenameRule>,
+}
+
+from_derive_input!(
+    GraphQLError,
+    WithAttributes<GraphQLErrorAttrs, BaseEnum<GraphQLErrorVariant, Generics>>,
+);
+
+fn get_error_name(error: &GraphQLError) -> String {
+    match &error.attrs.name {
+        Some(name) => name.clone(),
+        None => error.ident.to_string(),
+    }
+}
+
+fn get_code(variant: &GraphQLErrorVariant) -> String {
+    calc_enum_item_name(
+        variant.attrs.code.as_deref(),
+        &variant.ident.to_string(),
+        None,
+    )
+}
+
+fn get_variant_pattern(variant: &GraphQLErrorVariant) -> TokenStream {
+    let ident = &variant.ident;
+    match variant.fields.style {
+        Style::Unit => quote!(#ident),
+        Style::Tuple => quote!(#ident(..)),
+        Style::Struct => {
+            let fields = variant
+                .fields
+                .iter()
+                .filter(|field| !field.attrs.skip)
+                .map(|field| &field.ident);
+            quote!(#ident { #(#fields,)* .. })
+        }
+    }
+}
+
+/// The identifier of a field bound in the pattern of the variant, `_0` for the first field of a
+/// tuple variant
+fn get_field_binding(index: usize, field: &GraphQLErrorField) -> syn::Ident {
+    match &field.ident {
+        Some(ident) => ident.clone(),
+        None => format_ident!("_{}", index),
+    }
+}
+
+/// Replace the positional arguments of the message, e.g. `{0}`, with the bindings of the
+/// fields, e.g. `{_0}`
+fn get_message_format(message: &str) -> String {
+    let mut format = String::with_capacity(message.len());
+    let mut chars = message.chars().peekable();
+    while let Some(c) = chars.next() {
+        format.push(c);
+        if c != '{' {
+            continue;
+        }
+        if chars.peek() == Some(&'{') {
+            format.push(chars.next().unwrap());
+        } else if chars.peek().is_some_and(char::is_ascii_digit) {
+            format.push('_');
+        }
+    }
+    format
+}
+
+fn get_variant_message_code(variant: &GraphQLErrorVariant) -> TokenStream {
+    let ident = &variant.ident;
+    let Some(message) = &variant.attrs.message else {
+        return quote! {
+            Self::#ident { .. } => ToString::to_string(self),
+        };
+    };
+    let bindings = variant
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| get_field_binding(index, field));
+    let pattern = match variant.fields.style {
+        Style::Unit => quote!(#ident),
+        Style::Tuple => quote!(#ident(#(#bindings),*)),
+        Style::Struct => quote!(#ident { #(#bindings),* }),
+    };
+    let format = syn::LitStr::new(&get_message_format(message), proc_macro2::Span::call_site());
+    quote! {
+        Self::#pattern => format!(#format),
+    }
+}
+
+fn get_error_message_code(error: &GraphQLError) -> TokenStream {
+    let arms = error.data.iter().map(get_variant_message_code);
+    quote! {
+        #[allow(unused_variables)]
+        fn message(&self) -> String {
+            match self {
+                #(#arms)*
+            }
+        }
+    }
+}
+
+fn get_variant_extensions_code(
+    error: &GraphQLError,
+    variant: &GraphQLErrorVariant,
+) -> darling::Result<TokenStream> {
+    let code = get_code(variant);
+    let fields = match variant.fields.style {
+        Style::Struct => variant
+            .fields
+            .iter()
+            .filter(|field| !field.attrs.skip)
+            .map(|field| {
+                let ident = &field.ident;
+                let name = calc_field_name(
+                    field.attrs.name.as_deref(),
+                    &ident.as_ref().unwrap().to_string(),
+                    error.attrs.rename_fields.as_ref(),
+                );
+                quote! {
+                    extensions.set(#name, Clone::clone(#ident));
+                }
+            })
+            .collect(),
+        _ => quote!(),
+    };
+    Ok(quote! {
+        extensions.set("code", #code);
+        #fields
+    })
+}
+
+fn get_error_extensions_code(error: &GraphQLError) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = &error.ident;
+
+    let arms = error
+        .data
+        .iter()
+        .map(|variant| {
+            let pattern = get_variant_pattern(variant);
+            let extensions = get_variant_extensions_code(error, variant).into_token_stream();
+            quote! {
+                #ident::#pattern => {
+                    #extensions
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Ok(quote! {
+        fn error_extensions(&self) -> #crate_name::ErrorExtensionValues {
+            let mut extensions = #crate_name::ErrorExtensionValues::default();
+            match self {
+                #(#arms)*
+            }
+            extensions
+        }
+    })
+}
+
+fn impl_graphql_error(error: &GraphQLError) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = &error.ident;
+    let error_name = get_error_name(error);
+    let error_message = get_error_message_code(error);
+    let error_extensions = get_error_extensions_code(error)?;
+    let (impl_generics, ty_generics, where_clause) = error.generics.split_for_impl();
+
+    let codes = error
+        .data
+        .iter()
+        .map(|variant| {
+            let code = get_code(variant);
+            let description = match &variant.attrs.doc {
+                Some(doc) => quote!(Some(#doc)),
+                None => quote!(None),
+            };
+            quote! {
+                #crate_name::ErrorCode {
+                    error: #error_name,
+                    code: #code,
+                    description: #description,
+                },
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Ok(quote! {
+        impl #impl_generics #crate_name::internal::GraphQLError for #ident #ty_generics #where_clause {
+            const ERROR_CODES: &'static [#crate_name::ErrorCode] = &[
+                #(#codes)*
+            ];
+            #error_message
+            #error_extensions
+        }
+    })
+}
+
+fn impl_register(error: &GraphQLError) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = &error.ident;
+    let (impl_generics, ty_generics, where_clause) = error.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #crate_name::internal::Register for #ident #ty_generics #where_clause {
+            fn register(registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry {
+                registry.register_error::<Self>()
+            }
+        }
+    })
+}
+
+fn impl_error_extensions(error: &GraphQLError) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = &error.ident;
+    let (impl_generics, ty_generics, where_clause) = error.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #crate_name::ErrorExtensions for #ident #ty_generics #where_clause {
+            fn extend(&self) -> #crate_name::Error {
+                let mut err = #crate_name::Error::new(#crate_name::internal::GraphQLError::message(self));
+                err.extensions = Some(#crate_name::internal::GraphQLError::error_extensions(self));
+                err
+            }
+        }
+    })
+}
+
+impl ToTokens for GraphQLError {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let impl_graphql_error = impl_graphql_error(self).into_token_stream();
+        let impl_register = impl_register(self).into_token_stream();
+        let impl_error_extensions = impl_error_extensions(self).into_token_stream();
+        tokens.extend(quote! {
+            #impl_graphql_error
+            #impl_register
+            #impl_error_extensions
+        });
+    }
+}
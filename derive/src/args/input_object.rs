@@ -1,9 +1,10 @@
-use darling::FromAttributes;
 use proc_macro2::TokenStream;
 use quote::ToTokens;
 use quote::quote;
+use quote::quote_spanned;
 use syn::Generics;
 use syn::Path;
+use syn::spanned::Spanned;
 
 use crate::args::common;
 use crate::utils::common::CommonField;
@@ -12,27 +13,20 @@ use crate::utils::common::EMPTY_ARGS;
 use crate::utils::common::GetArgs;
 use crate::utils::common::GetFields;
 use crate::utils::crate_name::get_crate_name;
+use crate::utils::default_value::DefaultValue;
+use crate::utils::deprecation::Deprecation;
 use crate::utils::derive_types::BaseStruct;
 use crate::utils::derive_types::NamedField;
 use crate::utils::error::IntoTokenStream;
 use crate::utils::macros::*;
-use crate::utils::register_attr::RegisterAttr;
+use crate::utils::paired_attrs::ObjectAttrs;
+use crate::utils::paired_attrs::ObjectFieldAttrs;
 use crate::utils::rename_rule::RenameRule;
 use crate::utils::with_attributes::WithAttributes;
 use crate::utils::with_context::MakeContext;
 use crate::utils::with_context::WithContext;
 use crate::utils::with_doc::WithDoc;
 
-#[derive(FromAttributes, Debug, Clone)]
-#[darling(attributes(graphql))]
-pub struct InputObjectFieldAttrs {
-    #[darling(default)]
-    pub skip: bool,
-
-    #[darling(default)]
-    pub name: Option<String>,
-}
-
 #[derive(Default, Debug, Clone)]
 pub struct InputObjectFieldContext {
     pub rename_fields: Option<RenameRule>,
@@ -40,33 +34,12 @@ pub struct InputObjectFieldContext {
 
 from_field!(
     InputObjectField,
-    WithAttributes<
-        WithDoc<InputObjectFieldAttrs>,
-        WithContext<InputObjectFieldContext, NamedField>,
-    >,
+    WithAttributes<WithDoc<ObjectFieldAttrs>, WithContext<InputObjectFieldContext, NamedField>>,
 );
 
-#[derive(FromAttributes, Debug, Clone)]
-#[darling(attributes(graphql))]
-pub struct InputObjectAttrs {
-    #[darling(default)]
-    pub name: Option<String>,
-
-    #[darling(default)]
-    #[darling(rename = "get_type_name")]
-    pub type_name: bool,
-
-    #[darling(default)]
-    pub rename_fields: Option<RenameRule>,
-
-    #[darling(default, multiple)]
-    #[darling(rename = "register")]
-    pub registers: Vec<RegisterAttr>,
-}
-
 from_derive_input!(
     InputObject,
-    WithAttributes<WithDoc<InputObjectAttrs>, BaseStruct<InputObjectField, Generics>>,
+    WithAttributes<WithDoc<ObjectAttrs>, BaseStruct<InputObjectField, Generics>>,
     ctx,
 );
 
@@ -106,7 +79,11 @@ impl CommonObject for InputObject {
 
 impl CommonField for InputObjectField {
     fn get_name(&self) -> Option<&str> {
-        self.attrs.name.as_deref()
+        self.attrs
+            .input
+            .name
+            .as_deref()
+            .or(self.attrs.name.as_deref())
     }
 
     fn get_ident(&self) -> darling::Result<&syn::Ident> {
@@ -118,16 +95,28 @@ impl CommonField for InputObjectField {
     }
 
     fn get_skip(&self) -> bool {
-        self.attrs.skip
+        self.attrs.skip || self.attrs.input.skip
     }
 
     fn get_doc(&self) -> darling::Result<Option<String>> {
         Ok(self.attrs.doc.clone())
     }
 
+    fn get_deprecation(&self) -> darling::Result<Deprecation> {
+        Ok(self.attrs.deprecation.clone())
+    }
+
+    fn get_default_value(&self) -> darling::Result<DefaultValue> {
+        Ok(self.attrs.default.clone())
+    }
+
     fn get_field_rename_rule(&self) -> Option<&RenameRule> {
         self.ctx.rename_fields.as_ref()
     }
+
+    fn get_flatten(&self) -> bool {
+        self.attrs.flatten
+    }
 }
 
 impl GetFields<InputObjectField> for InputObject {
@@ -142,16 +131,37 @@ impl GetArgs<()> for InputObjectField {
     }
 }
 
-fn get_define_field(field: &impl CommonField) -> darling::Result<TokenStream> {
+pub fn get_define_field(field: &impl CommonField) -> darling::Result<TokenStream> {
     let description = common::field_description(field)?;
     let get_new_input_value_code = common::get_new_input_value_code(field)?;
+    let field_name = common::get_input_field_name(field)?;
+    let default_value = field.get_default_value()?;
+    let default =
+        common::input_default_value_code(&default_value, field.get_type()?, quote!(field));
+    let deprecation = common::input_deprecation_code(
+        &field.get_deprecation()?,
+        &default_value,
+        field.get_type()?,
+        quote!(field),
+        &format!("Input field \"{}\"", field_name),
+    );
     Ok(quote! {
         #get_new_input_value_code
         #description
+        #default
+        #deprecation
         let object = object.field(field);
     })
 }
 
+fn get_define_flattened_field(field: &impl CommonField) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ty = field.get_type()?;
+    Ok(quote! {
+        let object = <#ty as #crate_name::internal::FlattenInputObject>::define_fields(object);
+    })
+}
+
 fn get_define_fields<O, F>(object: &O) -> darling::Result<TokenStream>
 where
     O: GetFields<F>,
@@ -161,35 +171,212 @@ where
         .get_fields()?
         .iter()
         .filter(|field| !field.get_skip())
-        .map(|field| get_define_field(field).into_token_stream())
+        .map(|field| {
+            if field.get_flatten() {
+                get_define_flattened_field(field).into_token_stream()
+            } else {
+                get_define_field(field).into_token_stream()
+            }
+        })
         .collect())
 }
 
-fn impl_register(object: &InputObject) -> darling::Result<TokenStream> {
+fn check_fields(object: &InputObject) -> darling::Result<()> {
+    let mut errors = darling::Error::accumulator();
+    if object.attrs.patch_for.is_some() {
+        for field in get_flattened_fields(object) {
+            errors.push(
+                darling::Error::custom("`flatten` is not supported with `patch_for`")
+                    .with_span(&field.ident),
+            );
+        }
+    }
+    if object.attrs.input_name.is_none() {
+        let attrs = &object.attrs;
+        let output_attrs = [
+            ("root", attrs.root),
+            ("mark", !attrs.marks.is_empty()),
+            ("implements", !attrs.impls.is_empty()),
+            ("interface_default", !attrs.interface_defaults.is_empty()),
+            ("extends_union", !attrs.extends_unions.is_empty()),
+            ("computed", !attrs.computed.is_empty()),
+            ("complex", attrs.complex),
+        ];
+        for (name, _) in output_attrs.iter().filter(|(_, is_set)| *is_set) {
+            errors.push(
+                darling::Error::custom(format!(
+                    "`{}` is a SimpleObject attribute, it requires `input_name`",
+                    name
+                ))
+                .with_span(&object.ident),
+            );
+        }
+        for field in &object.data.fields {
+            if field.attrs.output.is_set() || field.attrs.partial_list || field.attrs.with.is_some()
+            {
+                errors.push(
+                    darling::Error::custom(
+                        "SimpleObject field attributes require `input_name` on the type",
+                    )
+                    .with_span(&field.ident),
+                );
+            }
+        }
+    }
+    for field in object
+        .data
+        .fields
+        .iter()
+        .filter(|field| field.attrs.flatten)
+    {
+        let conflict = if field.attrs.skip {
+            Some("skip")
+        } else if field.attrs.name.is_some() {
+            Some("name")
+        } else if !matches!(field.attrs.deprecation, Deprecation::NoDeprecated) {
+            Some("deprecation")
+        } else {
+            None
+        };
+        if let Some(conflict) = conflict {
+            errors.push(
+                darling::Error::custom(format!("`flatten` can't be used with `{}`", conflict))
+                    .with_span(&field.ident),
+            );
+        }
+    }
+    errors.finish()
+}
+
+fn get_flattened_fields(object: &InputObject) -> impl Iterator<Item = &InputObjectField> {
+    object
+        .data
+        .fields
+        .iter()
+        .filter(|field| !field.get_skip() && field.get_flatten())
+}
+
+/// the names of the flattened input objects are only known by their `FlattenInputObject`, they
+/// are checked at compile time
+fn get_check_field_names_code(object: &InputObject) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let names = object
+        .data
+        .fields
+        .iter()
+        .filter(|field| !field.get_skip() && !field.get_flatten())
+        .map(|field| Ok((common::get_input_field_name(field)?, field.ident.span())))
+        .collect::<darling::Result<Vec<_>>>()?;
+    let flattened = get_flattened_fields(object)
+        .map(|field| (&field.ty, field.ident.span()))
+        .collect::<Vec<_>>();
+    Ok(common::get_check_flattened_names_code(
+        &object.ident,
+        &names,
+        &flattened,
+        quote!(#crate_name::internal::FlattenInputObject),
+    ))
+}
+
+/// the fields of the input object are defined and parsed by `FlattenInputObject`, so they can be
+/// flattened into other input objects
+fn impl_flatten_input_object(object: &InputObject) -> darling::Result<TokenStream> {
     let crate_name = get_crate_name();
     let ident = &object.ident;
+
     let register_nested_types = common::get_nested_type_register_code(object).into_token_stream();
+    let define_fields = get_define_fields(object)?;
+    let parse_fields = if object.attrs.collect_errors {
+        impl_collected_from_value(object)?
+    } else {
+        let fields_value = get_fields_value(object);
+        let fields_usage = get_fields_usage(object)?;
+        quote! {
+            #fields_value
+            #fields_usage
+        }
+    };
+    let field_names = object
+        .data
+        .fields
+        .iter()
+        .filter(|field| !field.get_skip() && !field.get_flatten())
+        .map(common::get_input_field_name)
+        .collect::<darling::Result<Vec<_>>>()?;
+
+    let mut register_flattened = Vec::new();
+    let mut flattened_names = Vec::new();
+    for field in get_flattened_fields(object) {
+        let ty = &field.ty;
+        register_flattened.push(quote! {
+            let registry = <#ty as #crate_name::internal::FlattenInputObject>::register_fields(registry);
+        });
+        flattened_names.push(quote! {
+            <#ty as #crate_name::internal::FlattenInputObject>::FIELD_NAMES
+        });
+    }
+
+    let (impl_generics, ty_generics, where_clause) = object.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #crate_name::internal::FlattenInputObject for #ident #ty_generics #where_clause {
+            fn register_fields(registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry {
+                #register_nested_types
+                #(#register_flattened)*
+                registry
+            }
+
+            fn define_fields(object: #crate_name::dynamic::InputObject) -> #crate_name::dynamic::InputObject {
+                #define_fields
+                object
+            }
+
+            fn from_fields(__object: &#crate_name::dynamic::ObjectAccessor<'_>) -> #crate_name::internal::InputValueResult<Self> {
+                #parse_fields
+            }
+
+            const FIELD_NAMES: #crate_name::internal::FieldNames = #crate_name::internal::FieldNames {
+                names: &[#(#field_names),*],
+                flattened: &[#(#flattened_names),*],
+            };
+        }
+    })
+}
+
+fn impl_register(object: &InputObject) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = &object.ident;
 
     let define_object = common::impl_define_input_object();
-    let define_fields = get_define_fields(object)?;
     let description = common::object_description(object.get_doc()?.as_deref())?;
     let register_object_code = common::register_object_code();
+    let check_field_names = get_check_field_names_code(object)?;
 
     let register_attr = &object.attrs.registers;
+    let (impl_generics, ty_generics, where_clause) = object.generics.split_for_impl();
+
+    // a paired type is registered by the `Register` of its object
+    let (register_trait, register_fn) = if object.attrs.input_name.is_some() {
+        (quote!(RegisterInputObject), quote!(register_input_object))
+    } else {
+        (quote!(Register), quote!(register))
+    };
 
     Ok(quote! {
-        impl #crate_name::internal::Register for #ident {
-            fn register(registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry {
+        impl #impl_generics #crate_name::internal::#register_trait for #ident #ty_generics #where_clause {
+            fn #register_fn(registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry {
 
                 #( #register_attr )*
 
-                #register_nested_types
+                let registry = <Self as #crate_name::internal::FlattenInputObject>::register_fields(registry);
 
                 #define_object
 
                 #description
 
-                #define_fields
+                #check_field_names
+
+                let object = <Self as #crate_name::internal::FlattenInputObject>::define_fields(object);
 
                 #register_object_code
             }
@@ -197,11 +384,52 @@ fn impl_register(object: &InputObject) -> darling::Result<TokenStream> {
     })
 }
 
+/// adds the field to `__fields`, the input object value of `ToInputValue`
+pub fn get_field_to_input_value(
+    field: &impl CommonField,
+    value: TokenStream,
+) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    if field.get_skip() {
+        return Ok(quote!());
+    }
+    if field.get_flatten() {
+        return Ok(quote! {
+            #crate_name::internal::extend_input_fields(&mut __fields, #value);
+        });
+    }
+    let field_name = common::get_input_field_name(field)?;
+    Ok(quote! {
+        #crate_name::internal::insert_input_field(&mut __fields, #field_name, #value);
+    })
+}
+
+fn impl_to_input_value(object: &InputObject) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let fields = object
+        .data
+        .fields
+        .iter()
+        .map(|field| {
+            let ident = &field.ident;
+            get_field_to_input_value(field, quote!(&self.#ident)).into_token_stream()
+        })
+        .collect::<Vec<_>>();
+    common::impl_to_input_value(
+        object,
+        quote! {
+            let mut __fields = Default::default();
+            #(#fields)*
+            #crate_name::Value::Object(__fields)
+        },
+    )
+}
+
 fn get_item_ident(index: usize, ident: &syn::Ident) -> syn::Ident {
     syn::Ident::new(&format!("field{}", index), ident.span())
 }
 
-fn get_field_value(index: usize, field: &InputObjectField) -> darling::Result<TokenStream> {
+pub fn get_field_value(index: usize, field: &impl CommonField) -> darling::Result<TokenStream> {
     let crate_name = get_crate_name();
     let field_ident = field.get_ident()?;
     let item = get_item_ident(index, field_ident);
@@ -211,8 +439,22 @@ fn get_field_value(index: usize, field: &InputObjectField) -> darling::Result<To
             let #item = Default::default();
         });
     }
+    if field.get_flatten() {
+        let ty = field.get_type()?;
+        return Ok(quote! {
+            let #item = <#ty as #crate_name::internal::FlattenInputObject>::from_fields(__object).map_err(#crate_name::internal::InputValueError::propagate)?;
+        });
+    }
+    if let Some(value) = field.get_default_value()?.get_value_code(field.get_type()?) {
+        return Ok(quote! {
+            let #item = match __object.get(#field_name) {
+                Some(__value) => #crate_name::internal::FromValue::from_value(Ok(__value)).map_err(|e| e.in_field(#field_name))?,
+                None => #value,
+            };
+        });
+    }
     Ok(quote! {
-        let #item = #crate_name::internal::FromValue::from_value(__object.try_get(#field_name)).map_err(|e| e.into_field_error(#field_name))?;
+        let #item = #crate_name::internal::FromValue::from_value(__object.try_get(#field_name)).map_err(|e| e.in_field(#field_name))?;
     })
 }
 
@@ -226,7 +468,7 @@ fn get_fields_value(object: &InputObject) -> TokenStream {
         .collect()
 }
 
-fn get_field_usage(index: usize, field: &impl CommonField) -> darling::Result<TokenStream> {
+pub fn get_field_usage(index: usize, field: &impl CommonField) -> darling::Result<TokenStream> {
     let field_ident = field.get_ident()?;
     let item = get_item_ident(index, field_ident);
     Ok(quote! {
@@ -253,32 +495,139 @@ where
     })
 }
 
+/// parse all the fields before returning the errors of all of them
+fn get_collected_field_value(
+    index: usize,
+    field: &InputObjectField,
+) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let field_ident = field.get_ident()?;
+    let item = get_item_ident(index, field_ident);
+    let field_name = common::get_input_field_name(field)?;
+    if field.get_skip() {
+        return Ok(quote! {
+            let #item = Some(Default::default());
+        });
+    }
+    if field.get_flatten() {
+        let ty = field.get_type()?;
+        return Ok(quote! {
+            let #item = match <#ty as #crate_name::internal::FlattenInputObject>::from_fields(__object) {
+                Ok(value) => Some(value),
+                Err(err) => {
+                    __errors.push(err.propagate());
+                    None
+                }
+            };
+        });
+    }
+    let value = match field.get_default_value()?.get_value_code(field.get_type()?) {
+        Some(value) => quote! {
+            match __object.get(#field_name) {
+                Some(__value) => #crate_name::internal::FromValue::from_value(Ok(__value)),
+                None => Ok(#value),
+            }
+        },
+        None => quote!(#crate_name::internal::FromValue::from_value(__object.try_get(#field_name))),
+    };
+    Ok(quote! {
+        let #item = match #value {
+            Ok(value) => Some(value),
+            Err(err) => {
+                __errors.push(err.in_field(#field_name));
+                None
+            }
+        };
+    })
+}
+
+fn impl_collected_from_value(object: &InputObject) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let mut fields_value = Vec::new();
+    let mut fields_usage = Vec::new();
+    for (index, field) in object.data.fields.iter().enumerate() {
+        let field_ident = field.get_ident()?;
+        let item = get_item_ident(index, field_ident);
+        fields_value.push(get_collected_field_value(index, field).into_token_stream());
+        fields_usage.push(quote!(#field_ident: #item.unwrap(),));
+    }
+    Ok(quote! {
+        let mut __errors: Vec<#crate_name::internal::InputValueError<Self>> = Vec::new();
+        #(#fields_value)*
+        if let Some(err) = #crate_name::internal::InputValueError::combine(__errors) {
+            return Err(err);
+        }
+        Ok(Self {
+            #(#fields_usage)*
+        })
+    })
+}
+
 fn impl_from_value(object: &InputObject) -> darling::Result<TokenStream> {
     let crate_name = get_crate_name();
     let ident = object.get_ident();
-    let fields_value = get_fields_value(object);
-    let fields_usage = get_fields_usage(object)?;
+    let (impl_generics, ty_generics, where_clause) = object.get_generics()?.split_for_impl();
     Ok(quote!(
-        impl #crate_name::internal::FromValue for #ident {
+        impl #impl_generics #crate_name::internal::FromValue for #ident #ty_generics #where_clause {
             fn from_value(__value: #crate_name::Result<#crate_name::dynamic::ValueAccessor>) -> #crate_name::internal::InputValueResult<Self> {
                 let __value = __value?;
                 let __object = __value.object()?;
-                #fields_value
-                #fields_usage
+                <Self as #crate_name::internal::FlattenInputObject>::from_fields(&__object)
             }
         }
     ))
 }
 
+/// absent fields keep the values of the target, see `ApplyPatch`
+fn impl_apply_to(object: &InputObject) -> darling::Result<TokenStream> {
+    let Some(patch_for) = &object.attrs.patch_for else {
+        return Ok(quote!());
+    };
+    let crate_name = get_crate_name();
+    let ident = &object.ident;
+    let apply_fields = object
+        .data
+        .fields
+        .iter()
+        .filter(|field| !field.get_skip())
+        .map(|field| {
+            let field_ident = &field.ident;
+            let ty = &field.ty;
+            quote_spanned! {ty.span()=>
+                #crate_name::internal::ApplyPatch::apply_patch(self.#field_ident, &mut target.#field_ident);
+            }
+        });
+    let (impl_generics, ty_generics, where_clause) = object.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            pub fn apply_to(self, target: &mut #patch_for) {
+                #(#apply_fields)*
+            }
+        }
+    })
+}
+
 impl ToTokens for InputObject {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let impl_object = common::impl_input_object(self).into_token_stream();
+        if let Err(err) = check_fields(self) {
+            tokens.extend(err.write_errors());
+            return;
+        }
+        let impl_object =
+            common::impl_input_object(self, self.attrs.input_name.as_deref()).into_token_stream();
         let impl_register = impl_register(self).into_token_stream();
         let impl_from_value = impl_from_value(self).into_token_stream();
+        let impl_to_input_value = impl_to_input_value(self).into_token_stream();
+        let impl_apply_to = impl_apply_to(self).into_token_stream();
+        let impl_flatten_input_object = impl_flatten_input_object(self).into_token_stream();
         tokens.extend(quote! {
             #impl_object
             #impl_register
             #impl_from_value
+            #impl_to_input_value
+            #impl_apply_to
+            #impl_flatten_input_object
         });
     }
 }
@@ -11,6 +11,7 @@ use syn::Meta;
 use syn::Path;
 use syn::Type;
 
+use crate::args::interface::native_async::desugar_async_methods;
 use crate::args::interface::others::impl_others_register;
 use crate::utils::attributes::Attributes;
 use crate::utils::common::CommonArg;
@@ -18,6 +19,7 @@ use crate::utils::common::CommonField;
 use crate::utils::common::CommonObject;
 use crate::utils::common::GetArgs;
 use crate::utils::common::GetFields;
+use crate::utils::default_value::DefaultValue;
 use crate::utils::deprecation::Deprecation;
 use crate::utils::error::IntoTokenStream;
 use crate::utils::impl_block::BaseFnArg;
@@ -25,6 +27,7 @@ use crate::utils::impl_block::BaseItemTrait;
 use crate::utils::impl_block::BaseMethod;
 use crate::utils::impl_block::FromItemTrait;
 use crate::utils::macros::*;
+use crate::utils::path_attr::PathAttr;
 use crate::utils::register_attr::RegisterAttr;
 use crate::utils::rename_rule::RenameRule;
 use crate::utils::with_attributes::WithAttributes;
@@ -34,6 +37,7 @@ use crate::utils::with_context::WithContext;
 use crate::utils::with_doc::WithDoc;
 use crate::utils::with_index::WithIndex;
 
+mod native_async;
 mod others;
 mod root;
 
@@ -71,6 +75,12 @@ pub struct InterfaceMethodArgAttrs {
     /// Description for this argument exposed in the GraphQL schema. Takes
     #[darling(default)]
     pub desc: Option<String>,
+
+    #[darling(default)]
+    pub deprecation: Deprecation,
+
+    #[darling(default)]
+    pub default: DefaultValue,
 }
 
 impl Attributes for InterfaceMethodArgAttrs {
@@ -166,6 +176,14 @@ impl CommonArg for InterfaceMethodArg {
     fn get_doc(&self) -> darling::Result<Option<String>> {
         Ok(self.attrs.desc.clone())
     }
+
+    fn get_deprecation(&self) -> darling::Result<Deprecation> {
+        Ok(self.attrs.deprecation.clone())
+    }
+
+    fn get_default_value(&self) -> darling::Result<DefaultValue> {
+        Ok(self.attrs.default.clone())
+    }
 }
 
 impl GetArgs<InterfaceMethodArg> for InterfaceMethod {
@@ -197,6 +215,10 @@ pub struct InterfaceAttrs {
     #[darling(default, multiple)]
     #[darling(rename = "auto_register")]
     pub auto_registers: Vec<RegisterAttr>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "extends")]
+    pub extends: Vec<PathAttr>,
 }
 
 impl Attributes for InterfaceAttrs {
@@ -205,6 +227,8 @@ impl Attributes for InterfaceAttrs {
 
 pub struct Interface(
     WithAttributes<WithDoc<InterfaceAttrs>, BaseItemTrait<InterfaceMethod, Generics>>,
+    /// the `{Trait}Boxed` trait of the native async methods
+    proc_macro2::TokenStream,
 );
 
 impl Deref for Interface {
@@ -215,14 +239,49 @@ impl Deref for Interface {
     }
 }
 
+impl Interface {
+    /// the interfaces this interface extends, e.g. `Node` in
+    /// `#[graphql(extends(Node))] trait Entity: Node`
+    pub fn get_parent_interfaces(&self) -> Vec<&Path> {
+        self.attrs.extends.iter().map(|path| &path.0).collect()
+    }
+}
+
+/// the extended interfaces must be supertraits, so the implementors of the interface implement them
+fn check_parent_interfaces(interface: &Interface) -> darling::Result<()> {
+    let mut errors = darling::Error::accumulator();
+    for PathAttr(path) in &interface.attrs.extends {
+        let name = path.to_token_stream().to_string();
+        let is_super_trait = interface
+            .super_traits
+            .iter()
+            .any(|super_trait| super_trait.to_token_stream().to_string() == name);
+        if !is_super_trait {
+            errors.push(
+                darling::Error::custom(format!(
+                    "the extended interface `{}` must be a supertrait of `{}`",
+                    name, interface.ident,
+                ))
+                .with_span(path),
+            );
+        }
+    }
+    errors.finish()
+}
+
 impl FromItemTrait for Interface {
     fn from_item_trait(item_trait: &mut ItemTrait) -> darling::Result<Self>
     where
         Self: Sized,
     {
-        let mut value = Self(FromItemTrait::from_item_trait(item_trait)?);
+        let mut value = Self(
+            FromItemTrait::from_item_trait(item_trait)?,
+            proc_macro2::TokenStream::new(),
+        );
+        check_parent_interfaces(&value)?;
         let ctx = MakeContext::make_context(&value);
         SetContext::set_context(&mut value.0, ctx);
+        value.1 = desugar_async_methods(item_trait)?;
         Ok(value)
     }
 }
@@ -303,8 +362,10 @@ impl ToTokens for Interface {
         let interface_struct = root::impl_interface(self).into_token_stream();
         let register = root::impl_register(self).into_token_stream();
         let register_other = impl_others_register(self).into_token_stream();
+        let boxed_methods = &self.1;
 
         tokens.extend(quote! {
+            #boxed_methods
             #interface_struct
             #register
             #register_other
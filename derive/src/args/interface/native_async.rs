@@ -0,0 +1,177 @@
+use proc_macro2::TokenStream;
+use quote::format_ident;
+use quote::quote;
+use syn::FnArg;
+use syn::ItemTrait;
+use syn::Lifetime;
+use syn::Signature;
+use syn::TraitItem;
+use syn::parse_quote;
+use syn::visit_mut::VisitMut;
+
+fn has_async_trait_attr(item_trait: &ItemTrait) -> bool {
+    item_trait.attrs.iter().any(|attr| {
+        attr.path()
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "async_trait")
+    })
+}
+
+fn boxed_lifetime() -> Lifetime {
+    parse_quote!('__boxed)
+}
+
+/// replaces the elided lifetimes with the lifetime of the boxed future
+struct NameElidedLifetimes;
+
+impl VisitMut for NameElidedLifetimes {
+    fn visit_type_reference_mut(&mut self, reference: &mut syn::TypeReference) {
+        if reference.lifetime.is_none() {
+            reference.lifetime = Some(boxed_lifetime());
+        }
+        syn::visit_mut::visit_type_reference_mut(self, reference);
+    }
+
+    fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+        if lifetime.ident == "_" {
+            *lifetime = boxed_lifetime();
+        }
+    }
+}
+
+/// The boxed version of an async method, callable on `dyn Trait`, and its implementation with
+/// the native method.
+///
+/// Methods with type or const parameters are not dyn compatible, they are rejected.
+fn get_boxed_method(
+    trait_ident: &syn::Ident,
+    sig: &Signature,
+    output: &syn::Type,
+) -> darling::Result<(TokenStream, TokenStream)> {
+    if sig.generics.type_params().next().is_some() || sig.generics.const_params().next().is_some() {
+        return Err(darling::Error::custom(
+            "async methods with type or const parameters are not supported, they can't be called on `dyn Trait`",
+        )
+        .with_span(&sig.generics));
+    }
+    let lifetime = boxed_lifetime();
+    let mut generics = sig.generics.clone();
+    let bounds: Vec<syn::WherePredicate> = generics
+        .lifetimes()
+        .map(|param| {
+            let param = &param.lifetime;
+            parse_quote!(#param: #lifetime)
+        })
+        .collect();
+    generics.params.insert(0, parse_quote!(#lifetime));
+    generics.make_where_clause().predicates.extend(bounds);
+
+    let mut arg_idents = Vec::new();
+    let inputs = sig
+        .inputs
+        .iter()
+        .enumerate()
+        .map(|(index, arg)| match arg {
+            FnArg::Receiver(receiver) => match (&receiver.reference, &receiver.mutability) {
+                (Some(_), Some(_)) => parse_quote!(&#lifetime mut self),
+                (Some(_), None) => parse_quote!(&#lifetime self),
+                (None, _) => FnArg::Receiver(receiver.clone()),
+            },
+            FnArg::Typed(arg) => {
+                let ident = format_ident!("__arg{}", index);
+                let mut ty = arg.ty.as_ref().clone();
+                NameElidedLifetimes.visit_type_mut(&mut ty);
+                arg_idents.push(ident.clone());
+                parse_quote!(#ident: #ty)
+            }
+        })
+        .collect::<Vec<FnArg>>();
+    let mut output = output.clone();
+    NameElidedLifetimes.visit_type_mut(&mut output);
+
+    let method_ident = &sig.ident;
+    let boxed_ident = format_ident!("{}_boxed", method_ident);
+    let (_, _, where_clause) = generics.split_for_impl();
+    let boxed_sig = quote! {
+        fn #boxed_ident #generics(#(#inputs),*) -> ::std::pin::Pin<::std::boxed::Box<
+            dyn ::std::future::Future<Output = #output> + ::std::marker::Send + #lifetime
+        >> #where_clause
+    };
+    let boxed_impl = quote! {
+        #boxed_sig {
+            ::std::boxed::Box::pin(<__T as #trait_ident>::#method_ident(self, #(#arg_idents),*))
+        }
+    };
+    Ok((quote!(#boxed_sig;), boxed_impl))
+}
+
+/// Rewrite `async fn` methods to `fn` methods returning `impl Future + Send`.
+///
+/// The methods get a `where Self: Sized` bound, so the trait stays dyn compatible and can be used
+/// as `Instance<dyn Trait>`. Implementors can still write `async fn` in their `impl` blocks.
+///
+/// On `dyn Trait`, the methods are called with their boxed version, e.g. `value_boxed` for
+/// `async fn value`, defined in the `{Trait}Boxed` supertrait and implemented for all the
+/// implementors of the trait. The returned tokens define this supertrait.
+///
+/// Traits with `#[async_trait]` are left as they are.
+pub fn desugar_async_methods(item_trait: &mut ItemTrait) -> darling::Result<TokenStream> {
+    if has_async_trait_attr(item_trait) {
+        return Ok(quote!());
+    }
+    let trait_ident = item_trait.ident.clone();
+    let mut boxed_sigs = Vec::new();
+    let mut boxed_impls = Vec::new();
+    for item in item_trait.items.iter_mut() {
+        let TraitItem::Fn(method) = item else {
+            continue;
+        };
+        if method.sig.asyncness.take().is_none() {
+            continue;
+        }
+        let output: syn::Type = match &method.sig.output {
+            syn::ReturnType::Default => parse_quote!(()),
+            syn::ReturnType::Type(_, ty) => ty.as_ref().clone(),
+        };
+        let (boxed_sig, boxed_impl) = get_boxed_method(&trait_ident, &method.sig, &output)?;
+        boxed_sigs.push(boxed_sig);
+        boxed_impls.push(boxed_impl);
+        method.sig.output = parse_quote! {
+            -> impl ::std::future::Future<Output = #output> + ::std::marker::Send
+        };
+        method
+            .sig
+            .generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote!(Self: Sized));
+        if let Some(block) = &method.default {
+            method.default = Some(parse_quote!({ async move #block }));
+        }
+    }
+    if boxed_sigs.is_empty() {
+        return Ok(quote!());
+    }
+
+    let boxed_trait_ident = format_ident!("{}Boxed", trait_ident);
+    item_trait.colon_token.get_or_insert_with(Default::default);
+    item_trait
+        .supertraits
+        .push(parse_quote!(#boxed_trait_ident));
+    let vis = &item_trait.vis;
+    let doc = format!(
+        "The async methods of [`{}`] returning boxed futures, callable on `dyn {}`",
+        trait_ident, trait_ident
+    );
+    Ok(quote! {
+        #[doc = #doc]
+        #vis trait #boxed_trait_ident {
+            #(#boxed_sigs)*
+        }
+
+        impl<__T: #trait_ident> #boxed_trait_ident for __T {
+            #(#boxed_impls)*
+        }
+    })
+}
@@ -79,11 +79,11 @@ impl FieldImplementor for OthersMethod<'_> {
     }
 
     fn get_resolve_code(&self) -> darling::Result<TokenStream> {
-        common::resolve_value_code()
+        common::resolve_method_value_code(self)
     }
 
     fn get_field_argument_definition(&self) -> darling::Result<TokenStream> {
-        common::get_argument_definitions(self.get_args()?)
+        common::get_argument_definitions(self.get_args()?, &common::get_field_name(self)?)
     }
 
     fn get_field_description_code(&self) -> darling::Result<TokenStream> {
@@ -167,6 +167,79 @@ fn use_field_code(method: &InterfaceMethod) -> darling::Result<TokenStream> {
     })
 }
 
+fn use_default_field_code(method: &InterfaceMethod) -> darling::Result<TokenStream> {
+    let field_var_ident = get_field_var_ident(method.index, &method.ident);
+    let method_name = method.ident.to_string();
+
+    Ok(quote! {
+        let object = if fields.contains(&#method_name) {
+            object.field(#field_var_ident)
+        } else {
+            object
+        };
+    })
+}
+
+fn default_field_signature_code(method: &InterfaceMethod) -> darling::Result<TokenStream> {
+    let method_name = method.ident.to_string();
+    let field_signature = common::get_field_signature_code(&OthersMethod(method))?;
+
+    Ok(quote! {
+        let signature = if fields.contains(&#method_name) {
+            #field_signature
+            signature
+        } else {
+            signature
+        };
+    })
+}
+
+fn impl_register_default_fields(input: &Interface) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = &input.ident;
+    let methods: Vec<_> = input
+        .get_fields()?
+        .iter()
+        .filter(|method| !method.get_skip())
+        .collect();
+
+    let define_fields = define_fields_code(input).into_token_stream();
+    let method_names: Vec<_> = methods
+        .iter()
+        .map(|method| method.ident.to_string())
+        .collect();
+    let fields_signature: TokenStream = methods
+        .iter()
+        .map(|method| default_field_signature_code(method).into_token_stream())
+        .collect();
+    let use_fields: TokenStream = methods
+        .iter()
+        .map(|method| use_default_field_code(method).into_token_stream())
+        .collect();
+
+    Ok(quote! {
+        const METHOD_NAMES: #crate_name::internal::FieldNames = #crate_name::internal::FieldNames {
+            names: &[#(#method_names),*],
+            flattened: &[],
+        };
+
+        fn register_default_fields(registry: #crate_name::internal::Registry, fields: &'static [&'static str]) -> #crate_name::internal::Registry {
+            #define_fields
+            let signature = #crate_name::internal::TypeSignature::new(<T as #crate_name::internal::Object>::get_object_type_name());
+            #fields_signature
+            let registry = registry.add_signature(signature);
+            registry.update_object(
+                <T as #crate_name::internal::Object>::get_object_type_name().as_ref(),
+                <dyn #ident as #crate_name::internal::Interface>::get_interface_type_name().as_ref(),
+                move |object| {
+                    #use_fields
+                    object
+                },
+            )
+        }
+    })
+}
+
 fn use_fields_code(input: &Interface) -> darling::Result<TokenStream> {
     Ok(input
         .get_fields()?
@@ -190,6 +263,11 @@ pub fn impl_others_register(input: &Interface) -> darling::Result<TokenStream> {
         register.with_generic(parse_quote!(T));
     });
 
+    // `T` implements the extended interfaces too
+    let parents = input.get_parent_interfaces();
+
+    let register_default_fields = impl_register_default_fields(input).into_token_stream();
+
     Ok(quote! {
 
         impl <T> #crate_name::internal::RegisterInstance<dyn #ident, T> for dyn #ident
@@ -203,7 +281,7 @@ pub fn impl_others_register(input: &Interface) -> darling::Result<TokenStream> {
             {
                 #( #auto_registers )*
                 #define_fields
-                registry.update_object(
+                let registry = registry.update_object(
                     <T as #crate_name::internal::Object>::get_object_type_name().as_ref(),
                     <dyn #ident as #crate_name::internal::Interface>::get_interface_type_name().as_ref(),
                     |object| {
@@ -211,8 +289,14 @@ pub fn impl_others_register(input: &Interface) -> darling::Result<TokenStream> {
                         let object = object.implement(<dyn #ident as #crate_name::internal::Interface>::get_interface_type_name().as_ref());
                         object
                     },
-                )
+                );
+                #(
+                    let registry = registry.register::<#crate_name::Instance<dyn #parents, T>>();
+                )*
+                registry
             }
+
+            #register_default_fields
         }
 
     })
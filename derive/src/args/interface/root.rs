@@ -35,7 +35,7 @@ impl FieldImplementor for InterfaceMethod {
     }
 
     fn get_field_argument_definition(&self) -> darling::Result<TokenStream> {
-        common::get_argument_definitions(self.get_args()?)
+        common::get_argument_definitions(self.get_args()?, &common::get_field_name(self)?)
     }
 
     fn get_field_description_code(&self) -> darling::Result<TokenStream> {
@@ -67,10 +67,32 @@ pub fn impl_interface(input: &Interface) -> darling::Result<TokenStream> {
         }
     });
 
+    let define_fields = common::get_define_fields_code(input)?;
+    let parents = input.get_parent_interfaces();
+
     Ok(quote! {
         #type_name
         impl #crate_name::internal::OutputTypeName for dyn #ident {}
-        impl #crate_name::internal::Interface for dyn #ident {}
+        impl #crate_name::internal::Interface for dyn #ident {
+            fn define_interface_fields(
+                object: #crate_name::dynamic::Interface,
+                interfaces: &mut Vec<std::borrow::Cow<'static, str>>,
+            ) -> #crate_name::dynamic::Interface {
+                let name = <Self as #crate_name::internal::Interface>::get_interface_type_name();
+                if interfaces.contains(&name) {
+                    return object;
+                }
+                interfaces.push(name);
+                #define_fields
+                #(
+                    let object = <dyn #parents as #crate_name::internal::Interface>::define_interface_fields(object, interfaces);
+                )*
+                object
+            }
+        }
+        #(
+            impl #crate_name::internal::InterfaceMark<dyn #parents> for dyn #ident {}
+        )*
     })
 }
 
@@ -80,7 +102,19 @@ pub fn impl_register(input: &Interface) -> darling::Result<TokenStream> {
     let register_nested_types = common::get_nested_type_register_code(input).into_token_stream();
 
     let description = common::object_description(input.get_doc()?.as_deref())?;
-    let define_fields = common::get_define_fields_code(input)?;
+    let rust_trait = ident.to_string();
+    let fields_signature = common::get_fields_signature_code(input)?;
+    let parents = input.get_parent_interfaces();
+    let add_signature = common::add_signature_code(
+        quote!(<Self as #crate_name::internal::Interface>::get_interface_type_name()),
+        quote! {
+            let signature = signature.rust_type(#rust_trait);
+            #fields_signature
+            #(
+                let signature = signature.implement(<dyn #parents as #crate_name::internal::Interface>::get_interface_type_name());
+            )*
+        },
+    );
     let register_code = common::register_object_code();
 
     let register_attr = &input.attrs.registers;
@@ -93,11 +127,22 @@ pub fn impl_register(input: &Interface) -> darling::Result<TokenStream> {
 
                 #register_nested_types
 
+                #(
+                    let registry = registry.register::<dyn #parents>();
+                )*
+
                 // todo rename to interface
                 let object = #crate_name::dynamic::Interface::new(<Self as #crate_name::internal::Interface>::get_interface_type_name().as_ref());
 
                 #description
-                #define_fields
+                // the first one is the interface itself, the others are the extended interfaces
+                let mut interfaces = Vec::new();
+                let object = <Self as #crate_name::internal::Interface>::define_interface_fields(object, &mut interfaces);
+                let object = interfaces
+                    .into_iter()
+                    .skip(1)
+                    .fold(object, |object, name| object.implement(name));
+                #add_signature
                 #register_code
             }
         }
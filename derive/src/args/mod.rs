@@ -1,7 +1,9 @@
 pub use app::App;
+pub use enum_extension::EnumExtension;
 pub use expand_object::ExpandObject;
 pub use expand_object_fields::ExpandObjectFields;
 pub use gql_enum::Enum;
+pub use graphql_error::GraphQLError;
 pub use input_object::InputObject;
 pub use interface::Interface;
 pub use mutation::Mutation;
@@ -12,13 +14,16 @@ pub use resolved_object::ResolvedObject;
 pub use resolved_object_fields::ResolvedObjectFields;
 pub use scalar::Scalar;
 pub use simple_object::SimpleObject;
+pub use transparent::Transparent;
 pub use union::Union;
 
 mod app;
 mod common;
+mod enum_extension;
 mod expand_object;
 mod expand_object_fields;
 mod gql_enum;
+mod graphql_error;
 mod input_object;
 mod interface;
 mod mutation;
@@ -31,4 +36,5 @@ mod scalar;
 mod simple_object;
 #[cfg(test)]
 mod test_output;
+mod transparent;
 mod union;
@@ -7,10 +7,10 @@ use quote::quote;
 use syn::Generics;
 
 use crate::args::SimpleObject;
-use crate::args::simple_object::SimpleObjectAttrs;
 use crate::utils::derive_types::BaseStruct;
 use crate::utils::derive_types::UnitStruct;
 use crate::utils::macros::*;
+use crate::utils::paired_attrs::ObjectAttrs;
 use crate::utils::register_attr::RegisterAttr;
 use crate::utils::with_attributes::WithAttributes;
 use crate::utils::with_doc::WithDoc;
@@ -47,7 +47,7 @@ impl From<MutationRoot> for SimpleObject {
         SimpleObject(WithAttributes {
             attrs: WithDoc {
                 doc,
-                inner: SimpleObjectAttrs {
+                inner: ObjectAttrs {
                     root: false,
                     mutation_root: true,
                     name,
@@ -56,6 +56,13 @@ impl From<MutationRoot> for SimpleObject {
                     registers,
                     marks: vec![],
                     impls: vec![],
+                    interface_defaults: vec![],
+                    extends_unions: vec![],
+                    computed: vec![],
+                    complex: false,
+                    input_name: None,
+                    collect_errors: false,
+                    patch_for: None,
                 },
             },
             inner: BaseStruct {
@@ -1,4 +1,4 @@
-use darling::FromAttributes;
+use darling::ast::Style;
 use proc_macro2::TokenStream;
 use quote::ToTokens;
 use quote::quote;
@@ -6,67 +6,50 @@ use syn::Generics;
 use syn::Path;
 
 use crate::args::common;
+use crate::args::input_object;
+use crate::args::input_object::InputObjectFieldContext;
 use crate::utils::common::CommonField;
 use crate::utils::common::CommonObject;
 use crate::utils::common::EMPTY_ARGS;
 use crate::utils::common::GetArgs;
 use crate::utils::common::GetFields;
 use crate::utils::crate_name::get_crate_name;
+use crate::utils::deprecation::Deprecation;
 use crate::utils::derive_types::BaseEnum;
-use crate::utils::derive_types::NewtypeVariant;
+use crate::utils::derive_types::BaseField;
+use crate::utils::derive_types::BaseVariant;
 use crate::utils::error::IntoTokenStream;
 use crate::utils::macros::*;
-use crate::utils::register_attr::RegisterAttr;
+use crate::utils::paired_attrs::ObjectFieldAttrs;
+use crate::utils::paired_attrs::UnionAttrs;
+use crate::utils::paired_attrs::UnionVariantAttrs;
 use crate::utils::rename_rule::RenameRule;
 use crate::utils::with_attributes::WithAttributes;
 use crate::utils::with_context::MakeContext;
 use crate::utils::with_context::WithContext;
 use crate::utils::with_doc::WithDoc;
 
-#[derive(FromAttributes, Debug, Clone)]
-#[darling(attributes(graphql))]
-pub struct OneOfInputFieldAttrs {
-    #[darling(default)]
-    pub skip: bool,
-
-    #[darling(default)]
-    pub name: Option<String>,
-}
-
 #[derive(Default, Debug, Clone)]
 pub struct OneOfInputFieldContext {
     pub rename_fields: Option<RenameRule>,
 }
 
+from_field!(
+    OneOfInputVariantField,
+    WithAttributes<WithDoc<ObjectFieldAttrs>, WithContext<InputObjectFieldContext, BaseField>>,
+);
+
 from_variant!(
     OneOfInputField,
     WithAttributes<
-        WithDoc<OneOfInputFieldAttrs>,
-        WithContext<OneOfInputFieldContext, NewtypeVariant>,
+        WithDoc<UnionVariantAttrs>,
+        WithContext<OneOfInputFieldContext, BaseVariant<OneOfInputVariantField>>,
     >,
 );
 
-#[derive(FromAttributes, Debug, Clone)]
-#[darling(attributes(graphql))]
-pub struct OneOfInputAttrs {
-    #[darling(default)]
-    pub name: Option<String>,
-
-    #[darling(default)]
-    #[darling(rename = "get_type_name")]
-    pub type_name: bool,
-
-    #[darling(default)]
-    pub rename_fields: Option<RenameRule>,
-
-    #[darling(default, multiple)]
-    #[darling(rename = "register")]
-    pub registers: Vec<RegisterAttr>,
-}
-
 from_derive_input!(
     OneOfInput,
-    WithAttributes<WithDoc<OneOfInputAttrs>, BaseEnum<OneOfInputField, Generics>>,
+    WithAttributes<WithDoc<UnionAttrs>, BaseEnum<OneOfInputField, Generics>>,
     ctx,
 );
 
@@ -114,7 +97,9 @@ impl CommonField for OneOfInputField {
     }
 
     fn get_type(&self) -> darling::Result<&syn::Type> {
-        Ok(&self.fields.ty)
+        self.get_newtype().ok_or_else(|| {
+            darling::Error::custom("OneOf input variant has no type").with_span(&self.ident)
+        })
     }
 
     fn get_skip(&self) -> bool {
@@ -125,6 +110,128 @@ impl CommonField for OneOfInputField {
         Ok(self.attrs.doc.clone())
     }
 
+    fn get_deprecation(&self) -> darling::Result<Deprecation> {
+        Ok(self.attrs.deprecation.clone())
+    }
+
+    fn get_field_rename_rule(&self) -> Option<&RenameRule> {
+        self.ctx.rename_fields.as_ref()
+    }
+}
+
+impl OneOfInputField {
+    /// the type of the newtype variants, struct variants are parsed from their own input object
+    fn get_newtype(&self) -> Option<&syn::Type> {
+        match self.fields.style {
+            Style::Tuple => self.fields.fields.first().map(|field| &field.ty),
+            _ => None,
+        }
+    }
+
+    /// unit variants are `Boolean` fields, which only accept `true`
+    fn is_unit(&self) -> bool {
+        self.fields.style == Style::Unit
+    }
+
+    fn is_struct(&self) -> bool {
+        self.fields.style == Style::Struct
+    }
+}
+
+fn check_fields(object: &OneOfInput) -> darling::Result<()> {
+    let mut errors = darling::Error::accumulator();
+    for field in &object.data {
+        match field.fields.style {
+            Style::Tuple if field.fields.len() != 1 => {
+                errors.push(
+                    darling::Error::unsupported_shape("tuple variant").with_span(&field.ident),
+                );
+            }
+            _ => {}
+        }
+        for variant_field in field.fields.iter() {
+            let attrs = &variant_field.attrs;
+            let unsupported = if attrs.flatten {
+                Some("flatten")
+            } else if attrs.output.is_set() && object.attrs.input_name.is_none() {
+                Some("output")
+            } else if attrs.partial_list {
+                Some("partial_list")
+            } else if attrs.with.is_some() {
+                Some("with")
+            } else {
+                None
+            };
+            let Some(unsupported) = unsupported else {
+                continue;
+            };
+            let error =
+                darling::Error::custom(format!("`{}` is not supported in variants", unsupported));
+            match &variant_field.ident {
+                Some(ident) => errors.push(error.with_span(ident)),
+                None => errors.push(error.with_span(&field.ident)),
+            }
+        }
+    }
+    errors.finish()
+}
+
+/// the name of the input object of struct variants, `{OneOfInput}{Variant}` by default
+///
+/// with `input_name`, `type_name` is the name of the object of the `Union` derive
+fn get_field_type_name_code(object: &OneOfInput, field: &OneOfInputField) -> TokenStream {
+    let crate_name = get_crate_name();
+    let type_name = field
+        .attrs
+        .type_name
+        .as_ref()
+        .filter(|_| object.attrs.input_name.is_none());
+    match type_name {
+        Some(name) => quote!(std::borrow::Cow::<'static, str>::Borrowed(#name)),
+        None => {
+            let variant_name = field.ident.to_string();
+            quote! {
+                std::borrow::Cow::<'static, str>::Owned(format!(
+                    "{}{}",
+                    <Self as #crate_name::internal::InputObject>::get_input_object_type_name(),
+                    #variant_name,
+                ))
+            }
+        }
+    }
+}
+
+impl CommonField for OneOfInputVariantField {
+    fn get_name(&self) -> Option<&str> {
+        self.attrs
+            .input
+            .name
+            .as_deref()
+            .or(self.attrs.name.as_deref())
+    }
+
+    fn get_ident(&self) -> darling::Result<&syn::Ident> {
+        self.ident
+            .as_ref()
+            .ok_or_else(|| darling::Error::custom("Field has no name").with_span(&self.ty))
+    }
+
+    fn get_type(&self) -> darling::Result<&syn::Type> {
+        Ok(&self.ty)
+    }
+
+    fn get_skip(&self) -> bool {
+        self.attrs.skip || self.attrs.input.skip
+    }
+
+    fn get_doc(&self) -> darling::Result<Option<String>> {
+        Ok(self.attrs.doc.clone())
+    }
+
+    fn get_deprecation(&self) -> darling::Result<Deprecation> {
+        Ok(self.attrs.deprecation.clone())
+    }
+
     fn get_field_rename_rule(&self) -> Option<&RenameRule> {
         self.ctx.rename_fields.as_ref()
     }
@@ -142,33 +249,115 @@ impl GetArgs<()> for OneOfInputField {
     }
 }
 
-fn get_define_field(field: &impl CommonField) -> darling::Result<TokenStream> {
-    let description = common::field_description(field)?;
-    let get_new_input_value_code = common::get_new_optional_input_value_code(field)?;
+/// the `Boolean` field of a unit variant only accepts `true`, which is added to its description
+fn get_unit_field_description(field: &OneOfInputField) -> darling::Result<TokenStream> {
+    let description = match field.get_doc()? {
+        Some(doc) => format!("{}\n\nMust be `true`.", doc),
+        None => "Must be `true`.".to_string(),
+    };
+    Ok(quote! {
+        let field = field.description(#description);
+    })
+}
+
+fn get_define_field(object: &OneOfInput, field: &OneOfInputField) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let description = if field.is_unit() {
+        get_unit_field_description(field)?
+    } else {
+        common::field_description(field)?
+    };
+    // the fields of a oneOf input object are nullable, so they can always be deprecated
+    let deprecation = common::field_deprecation_code(field)?;
+    let get_new_input_value_code = match field.get_newtype() {
+        Some(_) => common::get_new_optional_input_value_code(field)?,
+        None if field.is_unit() => {
+            let field_name = common::get_input_field_name(field)?;
+            quote! {
+                let field = #crate_name::dynamic::InputValue::new(#field_name, #crate_name::dynamic::TypeRef::named(#crate_name::dynamic::TypeRef::BOOLEAN));
+            }
+        }
+        None => {
+            let field_name = common::get_input_field_name(field)?;
+            let type_name = get_field_type_name_code(object, field);
+            quote! {
+                let field = #crate_name::dynamic::InputValue::new(#field_name, #crate_name::dynamic::TypeRef::named(#type_name));
+            }
+        }
+    };
     Ok(quote! {
         #get_new_input_value_code
         #description
+        #deprecation
         let object = object.field(field);
     })
 }
 
-fn get_define_fields<O, F>(object: &O) -> darling::Result<TokenStream>
-where
-    O: GetFields<F>,
-    F: CommonField,
-{
+fn get_define_fields(object: &OneOfInput) -> darling::Result<TokenStream> {
     Ok(object
         .get_fields()?
         .iter()
         .filter(|field| !field.get_skip())
-        .map(|field| get_define_field(field).into_token_stream())
+        .map(|field| get_define_field(object, field).into_token_stream())
         .collect())
 }
 
+fn get_define_struct_object(
+    object: &OneOfInput,
+    field: &OneOfInputField,
+) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let type_name = get_field_type_name_code(object, field);
+    let fields = field
+        .fields
+        .iter()
+        .filter(|field| !field.get_skip())
+        .map(|field| input_object::get_define_field(field).into_token_stream())
+        .collect::<Vec<_>>();
+    Ok(quote! {
+        let registry = {
+            let object = #crate_name::dynamic::InputObject::new(#type_name);
+            #(#fields)*
+            registry.register_type(object)
+        };
+    })
+}
+
+fn get_define_struct_objects(object: &OneOfInput) -> darling::Result<TokenStream> {
+    Ok(object
+        .data
+        .iter()
+        .filter(|field| !field.get_skip() && field.is_struct())
+        .map(|field| get_define_struct_object(object, field).into_token_stream())
+        .collect())
+}
+
+fn get_nested_type_register_code(object: &OneOfInput) -> TokenStream {
+    let mut types: Vec<&syn::Type> = Vec::new();
+    for field in object.data.iter().filter(|field| !field.get_skip()) {
+        let field_types: Vec<&syn::Type> = match field.get_newtype() {
+            Some(ty) => vec![ty],
+            None => field
+                .fields
+                .iter()
+                .filter(|field| !field.get_skip())
+                .map(|field| &field.ty)
+                .collect(),
+        };
+        for ty in field_types {
+            if !types.contains(&ty) {
+                types.push(ty);
+            }
+        }
+    }
+    common::get_types_register_code(&types)
+}
+
 fn impl_register(object: &OneOfInput) -> darling::Result<TokenStream> {
     let crate_name = get_crate_name();
     let ident = &object.ident;
-    let register_nested_types = common::get_nested_type_register_code(object).into_token_stream();
+    let register_nested_types = get_nested_type_register_code(object);
+    let define_struct_objects = get_define_struct_objects(object)?;
 
     let define_object = common::impl_define_input_object();
     let define_fields = get_define_fields(object)?;
@@ -176,15 +365,25 @@ fn impl_register(object: &OneOfInput) -> darling::Result<TokenStream> {
     let register_object_code = common::register_object_code();
 
     let register_attr = &object.attrs.registers;
+    let (impl_generics, ty_generics, where_clause) = object.generics.split_for_impl();
+
+    // a paired enum is registered by the `Register` of its union
+    let (register_trait, register_fn) = if object.attrs.input_name.is_some() {
+        (quote!(RegisterInputObject), quote!(register_input_object))
+    } else {
+        (quote!(Register), quote!(register))
+    };
 
     Ok(quote! {
-        impl #crate_name::internal::Register for #ident {
-            fn register(registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry {
+        impl #impl_generics #crate_name::internal::#register_trait for #ident #ty_generics #where_clause {
+            fn #register_fn(registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry {
 
                 #( #register_attr )*
 
                 #register_nested_types
 
+                #define_struct_objects
+
                 #define_object
                 let object = object.oneof();
 
@@ -202,24 +401,55 @@ fn get_item_ident(index: usize, ident: &syn::Ident) -> syn::Ident {
     syn::Ident::new(&format!("field{}", index), ident.span())
 }
 
-fn get_field_usage(index: usize, field: &impl CommonField) -> darling::Result<TokenStream> {
+fn get_field_usage(index: usize, field: &OneOfInputField) -> darling::Result<TokenStream> {
     let crate_name = get_crate_name();
     let field_ident = field.get_ident()?;
     let field_name = common::get_input_field_name(field)?;
+    if field.is_unit() {
+        let item = get_item_ident(index, field_ident);
+        return Ok(quote! {
+            if let Some(__field) = __object.get(#field_name) {
+                let #item: bool = #crate_name::internal::FromValue::from_value(Ok(__field)).map_err(|e| e.in_field(#field_name))?;
+                if !#item {
+                    return Err(#crate_name::internal::InputValueError::<bool>::custom("only `true` is accepted").in_field(#field_name));
+                }
+                return Ok(Self::#field_ident);
+            }
+        });
+    }
+    if field.is_struct() {
+        let values = field
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| input_object::get_field_value(index, field).into_token_stream())
+            .collect::<Vec<_>>();
+        let usages = field
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| input_object::get_field_usage(index, field).into_token_stream())
+            .collect::<Vec<_>>();
+        return Ok(quote! {
+            if let Some(__field) = __object.get(#field_name) {
+                let __object = __field.object()?;
+                #(#values)*
+                return Ok(Self::#field_ident {
+                    #(#usages)*
+                });
+            }
+        });
+    }
     let item = get_item_ident(index, field_ident);
     Ok(quote! {
         if let Some(__field) = __object.get(#field_name) {
-            let #item = #crate_name::internal::FromValue::from_value(Ok(__field)).map_err(|e| e.into_field_error(#field_name))?;
+            let #item = #crate_name::internal::FromValue::from_value(Ok(__field)).map_err(|e| e.in_field(#field_name))?;
             return Ok(Self::#field_ident(#item));
         }
     })
 }
 
-fn get_fields_usage<O, F>(object: &O) -> darling::Result<TokenStream>
-where
-    O: GetFields<F>,
-    F: CommonField,
-{
+fn get_fields_usage(object: &OneOfInput) -> darling::Result<TokenStream> {
     let items: Vec<_> = object
         .get_fields()?
         .iter()
@@ -245,8 +475,9 @@ fn impl_from_value(object: &OneOfInput) -> darling::Result<TokenStream> {
     let ident = object.get_ident();
     let fields_usage = get_fields_usage(object)?;
     let no_field_error = no_field_error();
+    let (impl_generics, ty_generics, where_clause) = object.get_generics()?.split_for_impl();
     Ok(quote!(
-        impl #crate_name::internal::FromValue for #ident {
+        impl #impl_generics #crate_name::internal::FromValue for #ident #ty_generics #where_clause {
             fn from_value(__value: #crate_name::Result<#crate_name::dynamic::ValueAccessor>) -> #crate_name::internal::InputValueResult<Self> {
                 let __value = __value?;
                 let __object = __value.object()?;
@@ -257,15 +488,88 @@ fn impl_from_value(object: &OneOfInput) -> darling::Result<TokenStream> {
     ))
 }
 
+fn get_variant_to_input_value(field: &OneOfInputField) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let field_ident = field.get_ident()?;
+    if field.get_skip() {
+        return Ok(quote! {
+            Self::#field_ident { .. } => #crate_name::Value::Null,
+        });
+    }
+    let field_name = common::get_input_field_name(field)?;
+    let (pattern, value) = match field.get_newtype() {
+        Some(_) => (
+            quote!(Self::#field_ident(value)),
+            quote!(#crate_name::internal::ToInputValue::to_input_value(value)),
+        ),
+        None if field.is_unit() => (
+            quote!(Self::#field_ident),
+            quote!(#crate_name::Value::Boolean(true)),
+        ),
+        None => {
+            let fields = field
+                .fields
+                .iter()
+                .filter(|field| !field.get_skip())
+                .map(|field| field.get_ident())
+                .collect::<darling::Result<Vec<_>>>()?;
+            let values = field
+                .fields
+                .iter()
+                .map(|field| {
+                    let ident = field.get_ident()?;
+                    input_object::get_field_to_input_value(field, quote!(#ident))
+                })
+                .collect::<darling::Result<Vec<_>>>()?;
+            (
+                quote!(Self::#field_ident { #(#fields,)* .. }),
+                quote! {{
+                    let mut __fields = Default::default();
+                    #(#values)*
+                    #crate_name::Value::Object(__fields)
+                }},
+            )
+        }
+    };
+    Ok(quote! {
+        #pattern => #crate_name::Value::Object(
+            std::iter::once((#crate_name::Name::new(#field_name), #value)).collect(),
+        ),
+    })
+}
+
+fn impl_to_input_value(object: &OneOfInput) -> darling::Result<TokenStream> {
+    let variants = object
+        .data
+        .iter()
+        .map(get_variant_to_input_value)
+        .collect::<darling::Result<Vec<_>>>()?;
+    common::impl_to_input_value(
+        object,
+        quote! {
+            match self {
+                #(#variants)*
+            }
+        },
+    )
+}
+
 impl ToTokens for OneOfInput {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let impl_object = common::impl_input_object(self).into_token_stream();
+        if let Err(err) = check_fields(self) {
+            tokens.extend(err.write_errors());
+            return;
+        }
+        let impl_object =
+            common::impl_input_object(self, self.attrs.input_name.as_deref()).into_token_stream();
         let impl_register = impl_register(self).into_token_stream();
         let impl_from_value = impl_from_value(self).into_token_stream();
+        let impl_to_input_value = impl_to_input_value(self).into_token_stream();
         tokens.extend(quote! {
             #impl_object
             #impl_register
             #impl_from_value
+            #impl_to_input_value
         });
     }
 }
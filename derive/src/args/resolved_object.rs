@@ -7,16 +7,20 @@ use syn::Path;
 
 use crate::args::common;
 use crate::args::common::get_add_implement_code;
+use crate::args::common::get_interface_default_code;
 use crate::args::common::get_interface_mark_code;
+use crate::args::common::get_interface_mark_signature_code;
 use crate::args::common::get_register_interface_code;
 use crate::utils::common::CommonInterfaceAttrs;
 use crate::utils::common::CommonObject;
 use crate::utils::crate_name::get_crate_name;
 use crate::utils::derive_types::BaseStruct;
 use crate::utils::error::IntoTokenStream;
+use crate::utils::interface_attr::InterfaceDefaultAttr;
 use crate::utils::interface_attr::InterfaceImplAttr;
 use crate::utils::interface_attr::InterfaceMarkAttr;
 use crate::utils::macros::*;
+use crate::utils::path_attr::PathAttr;
 use crate::utils::register_attr::RegisterAttr;
 use crate::utils::with_attributes::WithAttributes;
 use crate::utils::with_doc::WithDoc;
@@ -45,6 +49,14 @@ pub struct ResolvedObjectAttrs {
     #[darling(default, multiple)]
     #[darling(rename = "implements")]
     pub impls: Vec<InterfaceImplAttr>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "interface_default")]
+    pub interface_defaults: Vec<InterfaceDefaultAttr>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "extends_union")]
+    pub extends_unions: Vec<PathAttr>,
 }
 
 from_derive_input!(
@@ -86,6 +98,10 @@ impl CommonInterfaceAttrs for ResolvedObject {
     fn get_impls(&self) -> &Vec<InterfaceImplAttr> {
         &self.attrs.impls
     }
+
+    fn get_interface_defaults(&self) -> &Vec<InterfaceDefaultAttr> {
+        &self.attrs.interface_defaults
+    }
 }
 
 fn impl_register_interface(object: &impl CommonInterfaceAttrs) -> darling::Result<TokenStream> {
@@ -94,6 +110,18 @@ fn impl_register_interface(object: &impl CommonInterfaceAttrs) -> darling::Resul
     let register_interface_code = get_register_interface_code(object)?;
     let add_interfaces = get_interface_mark_code(object)?;
     let implement = get_add_implement_code(object, object.get_impls())?;
+    let interface_defaults = get_interface_default_code(object)?;
+    let mark_signature = get_interface_mark_signature_code(object)?;
+    // the signature is only needed to check the marked interfaces
+    let add_signature = (!object.get_marks().is_empty()).then(|| {
+        common::add_signature_code(
+            quote!(<Self as #crate_name::internal::Object>::get_object_type_name()),
+            quote! {
+                let signature = signature.rust_type(std::any::type_name::<Self>());
+                #mark_signature
+            },
+        )
+    });
     let (impl_generics, ty_generics, where_clause) = object.get_generics()?.split_for_impl();
 
     Ok(quote! {
@@ -101,6 +129,8 @@ fn impl_register_interface(object: &impl CommonInterfaceAttrs) -> darling::Resul
             fn __register_interface(registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry {
                 #register_interface_code
                 #implement
+                #interface_defaults
+                #add_signature
                 let registry = registry.update_object(
                     <Self as #crate_name::internal::Object>::get_object_type_name().as_ref(),
                     <Self as #crate_name::internal::Object>::get_object_type_name().as_ref(),
@@ -182,11 +212,13 @@ fn impl_registers_fn(object: &ResolvedObject) -> darling::Result<TokenStream> {
     let (impl_generics, ty_generics, where_clause) = object.get_generics()?.split_for_impl();
 
     let register_attr = &object.attrs.registers;
+    let extends_unions = common::get_extends_union_code(&object.attrs.extends_unions);
 
     Ok(quote! {
         impl #impl_generics #object_ident #ty_generics #where_clause {
             fn __registers(registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry {
                 #( #register_attr )*
+                #extends_unions
                 registry
             }
         }
@@ -3,6 +3,7 @@ use proc_macro2::Ident;
 use proc_macro2::TokenStream;
 use quote::ToTokens;
 use quote::quote;
+use quote::quote_spanned;
 use syn::Generics;
 use syn::Path;
 
@@ -17,8 +18,10 @@ use crate::utils::common::CommonObject;
 use crate::utils::common::GetArgs;
 use crate::utils::common::GetFields;
 use crate::utils::crate_name::get_crate_name;
+use crate::utils::default_value::DefaultValue;
 use crate::utils::deprecation::Deprecation;
 use crate::utils::error::IntoTokenStream;
+use crate::utils::errors_as_union::ErrorsAsUnion;
 use crate::utils::impl_block::BaseFnArg;
 use crate::utils::impl_block::BaseItemImpl;
 use crate::utils::impl_block::BaseMethod;
@@ -43,6 +46,12 @@ pub struct ResolvedObjectFieldsArgAttrs {
     /// Description for this argument exposed in the GraphQL schema. Takes
     #[darling(default)]
     pub desc: Option<String>,
+
+    #[darling(default)]
+    pub deprecation: Deprecation,
+
+    #[darling(default)]
+    pub default: DefaultValue,
 }
 
 impl Attributes for ResolvedObjectFieldsArgAttrs {
@@ -76,6 +85,12 @@ pub struct ResolvedObjectFieldsMethodAttrs {
 
     #[darling(default)]
     pub deprecation: Deprecation,
+
+    #[darling(default)]
+    pub partial_list: bool,
+
+    #[darling(default)]
+    pub errors_as_union: Option<ErrorsAsUnion>,
 }
 
 impl Attributes for ResolvedObjectFieldsMethodAttrs {
@@ -191,6 +206,12 @@ impl CommonField for ResolvedObjectFieldsMethod {
     fn get_field_rename_rule(&self) -> Option<&RenameRule> {
         self.ctx.rename_fields.as_ref()
     }
+    fn get_partial_list(&self) -> bool {
+        self.attrs.partial_list
+    }
+    fn get_errors_as_union(&self) -> Option<&ErrorsAsUnion> {
+        self.attrs.errors_as_union.as_ref()
+    }
     fn get_args_rename_rule(&self) -> Option<&RenameRule> {
         self.attrs
             .rename_args
@@ -223,6 +244,14 @@ impl CommonArg for ResolvedObjectFieldsArg {
     fn get_doc(&self) -> darling::Result<Option<String>> {
         Ok(self.attrs.desc.clone())
     }
+
+    fn get_deprecation(&self) -> darling::Result<Deprecation> {
+        Ok(self.attrs.deprecation.clone())
+    }
+
+    fn get_default_value(&self) -> darling::Result<DefaultValue> {
+        Ok(self.attrs.default.clone())
+    }
 }
 
 impl CommonMethod for ResolvedObjectFieldsMethod {
@@ -276,11 +305,11 @@ impl FieldImplementor for ResolvedObjectFieldsMethod {
     }
 
     fn get_resolve_code(&self) -> darling::Result<TokenStream> {
-        common::resolve_value_code()
+        common::resolve_method_value_code(self)
     }
 
     fn get_field_argument_definition(&self) -> darling::Result<TokenStream> {
-        common::get_argument_definitions(self.get_args()?)
+        common::get_argument_definitions(self.get_args()?, &common::get_field_name(self)?)
     }
 
     fn get_field_description_code(&self) -> darling::Result<TokenStream> {
@@ -318,26 +347,59 @@ where
     }
 }
 
+/// reject at compile time the fields already defined by the derive of the type, e.g. the fields of
+/// a `#[graphql(complex)]` simple object
+fn get_check_field_names_code(object: &ResolvedObjectFields) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let type_name = object.ty.to_token_stream().to_string();
+    object
+        .get_fields()?
+        .iter()
+        .filter(|field| !field.get_skip())
+        .map(|field| {
+            let name = common::get_field_name(field)?;
+            let message = format!(
+                "Duplicate field \"{}\" in `{}`, defined by the derive of the type and by its `ResolvedObjectFields`",
+                name, type_name
+            );
+            Ok(quote_spanned! {field.get_ident()?.span()=>
+                const {
+                    if <Self as #crate_name::internal::RegisterFns>::FIELD_NAMES.contains(#name) {
+                        panic!(#message);
+                    }
+                }
+            })
+        })
+        .collect()
+}
+
 fn impl_register(object: &ResolvedObjectFields) -> darling::Result<TokenStream> {
     let crate_name = get_crate_name();
     let ty = get_type_path(&object.ty)?;
     let register_nested_types = common::get_nested_type_register_code(object).into_token_stream();
     let define_object = common::impl_define_object();
     let define_fields = common::get_define_fields_code(object)?;
+    let fields_signature = common::get_fields_signature_code(object)?;
+    let add_signature = common::add_marked_signature_code(quote!(Self), fields_signature);
     let register_object_code = common::register_object_code();
     let register_fns = common::call_register_fns();
+    let check_field_names = get_check_field_names_code(object)?;
     let (impl_generics, _, where_clause) = object.get_generics()?.split_for_impl();
 
     Ok(quote! {
         impl #impl_generics #crate_name::internal::Register for #ty #where_clause {
             fn register(registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry {
 
+                #check_field_names
+
                 #register_nested_types
 
                 #define_object
 
                 #define_fields
 
+                #add_signature
+
                 #register_fns
 
                 #register_object_code
@@ -142,6 +142,18 @@ fn impl_from_value(scalar: &impl CommonObject) -> darling::Result<TokenStream> {
     ))
 }
 
+fn impl_to_input_value(scalar: &impl CommonObject) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = scalar.get_ident();
+    Ok(quote!(
+        impl #crate_name::internal::ToInputValue for #ident {
+            fn to_input_value(&self) -> #crate_name::Value {
+                #crate_name::ScalarValue::to_value(self)
+            }
+        }
+    ))
+}
+
 pub fn get_specified_by_url_code(scalar: &Scalar) -> darling::Result<TokenStream> {
     let specified_by_url = scalar.attrs.specified_by_url.as_deref();
     Ok(match specified_by_url {
@@ -199,6 +211,7 @@ impl ToTokens for Scalar {
         let impl_resolved_own = impl_resolved_own(self).into_token_stream();
         let impl_resolve_ref = impl_resolve_ref(self).into_token_stream();
         let impl_from_value = impl_from_value(self).into_token_stream();
+        let impl_to_input_value = impl_to_input_value(self).into_token_stream();
         let impl_register = impl_register(self).into_token_stream();
         let impl_suppress = impl_suppress_clippy_error(self);
         tokens.extend(quote! {
@@ -206,6 +219,7 @@ impl ToTokens for Scalar {
             #impl_resolved_own
             #impl_resolve_ref
             #impl_from_value
+            #impl_to_input_value
             #impl_register
             #impl_suppress
         })
@@ -1,10 +1,10 @@
-use darling::FromAttributes;
 use proc_macro2::Ident;
 use proc_macro2::TokenStream;
 use quote::ToTokens;
 use quote::quote;
 use syn::Generics;
 use syn::Path;
+use syn::spanned::Spanned;
 
 use crate::args::common;
 use crate::args::common::FieldImplementor;
@@ -20,29 +20,19 @@ use crate::utils::deprecation::Deprecation;
 use crate::utils::derive_types::BaseStruct;
 use crate::utils::derive_types::NamedField;
 use crate::utils::error::IntoTokenStream;
+use crate::utils::interface_attr::InterfaceDefaultAttr;
 use crate::utils::interface_attr::InterfaceImplAttr;
 use crate::utils::interface_attr::InterfaceMarkAttr;
 use crate::utils::macros::*;
-use crate::utils::register_attr::RegisterAttr;
+use crate::utils::paired_attrs::ComputedAttr;
+use crate::utils::paired_attrs::ObjectAttrs;
+use crate::utils::paired_attrs::ObjectFieldAttrs;
 use crate::utils::rename_rule::RenameRule;
 use crate::utils::with_attributes::WithAttributes;
 use crate::utils::with_context::MakeContext;
 use crate::utils::with_context::WithContext;
 use crate::utils::with_doc::WithDoc;
 
-#[derive(FromAttributes, Debug, Clone)]
-#[darling(attributes(graphql))]
-pub struct SimpleObjectFieldAttrs {
-    #[darling(default)]
-    pub skip: bool,
-
-    #[darling(default)]
-    pub name: Option<String>,
-
-    #[darling(default)]
-    pub deprecation: Deprecation,
-}
-
 #[derive(Default, Debug, Clone)]
 pub struct SimpleObjectFieldContext {
     pub rename_fields: Option<RenameRule>,
@@ -50,47 +40,12 @@ pub struct SimpleObjectFieldContext {
 
 from_field!(
     SimpleObjectField,
-    WithAttributes<
-        WithDoc<SimpleObjectFieldAttrs>,
-        WithContext<SimpleObjectFieldContext, NamedField>,
-    >,
+    WithAttributes<WithDoc<ObjectFieldAttrs>, WithContext<SimpleObjectFieldContext, NamedField>>,
 );
 
-#[derive(FromAttributes, Debug, Clone)]
-#[darling(attributes(graphql))]
-pub struct SimpleObjectAttrs {
-    #[darling(default)]
-    pub root: bool,
-
-    #[darling(skip)]
-    pub mutation_root: bool,
-
-    #[darling(default)]
-    pub name: Option<String>,
-
-    #[darling(default)]
-    #[darling(rename = "get_type_name")]
-    pub type_name: bool,
-
-    #[darling(default)]
-    pub rename_fields: Option<RenameRule>,
-
-    #[darling(default, multiple)]
-    #[darling(rename = "register")]
-    pub registers: Vec<RegisterAttr>,
-
-    #[darling(default, multiple)]
-    #[darling(rename = "mark")]
-    pub marks: Vec<InterfaceMarkAttr>,
-
-    #[darling(default, multiple)]
-    #[darling(rename = "implements")]
-    pub impls: Vec<InterfaceImplAttr>,
-}
-
 from_derive_input!(
     SimpleObject,
-    WithAttributes<WithDoc<SimpleObjectAttrs>, BaseStruct<SimpleObjectField, Generics>>,
+    WithAttributes<WithDoc<ObjectAttrs>, BaseStruct<SimpleObjectField, Generics>>,
     ctx,
 );
 
@@ -110,6 +65,10 @@ impl CommonInterfaceAttrs for SimpleObject {
     fn get_impls(&self) -> &Vec<InterfaceImplAttr> {
         &self.attrs.impls
     }
+
+    fn get_interface_defaults(&self) -> &Vec<InterfaceDefaultAttr> {
+        &self.attrs.interface_defaults
+    }
 }
 
 impl CommonObject for SimpleObject {
@@ -140,7 +99,11 @@ impl CommonObject for SimpleObject {
 
 impl CommonField for SimpleObjectField {
     fn get_name(&self) -> Option<&str> {
-        self.attrs.name.as_deref()
+        self.attrs
+            .output
+            .name
+            .as_deref()
+            .or(self.attrs.name.as_deref())
     }
 
     fn get_ident(&self) -> darling::Result<&Ident> {
@@ -152,7 +115,7 @@ impl CommonField for SimpleObjectField {
     }
 
     fn get_skip(&self) -> bool {
-        self.attrs.skip
+        self.attrs.skip || self.attrs.output.skip
     }
 
     fn get_doc(&self) -> darling::Result<Option<String>> {
@@ -164,6 +127,15 @@ impl CommonField for SimpleObjectField {
     fn get_field_rename_rule(&self) -> Option<&RenameRule> {
         self.ctx.rename_fields.as_ref()
     }
+    fn get_partial_list(&self) -> bool {
+        self.attrs.partial_list
+    }
+    fn get_flatten(&self) -> bool {
+        self.attrs.flatten
+    }
+    fn get_with(&self) -> Option<&syn::Path> {
+        self.attrs.with.as_ref()
+    }
 }
 
 impl FieldImplementor for SimpleObjectField {
@@ -173,15 +145,19 @@ impl FieldImplementor for SimpleObjectField {
 
     fn get_execute_code(&self) -> darling::Result<TokenStream> {
         let resolver_ident = get_resolver_ident(self)?;
+        let value = match self.get_with() {
+            Some(with) => quote!(#with(Self::#resolver_ident(parent))),
+            None => quote!(Self::#resolver_ident(parent)),
+        };
 
         Ok(quote! {
-            let parent = ctx.parent_value.try_downcast_ref::<Self>()?;
-            let value = Self::#resolver_ident(parent);
+            let parent = __get(ctx.parent_value.try_downcast_ref::<__P>()?);
+            let value = #value;
         })
     }
 
     fn get_resolve_code(&self) -> darling::Result<TokenStream> {
-        common::resolve_value_code()
+        common::resolve_value_code(self)
     }
 
     fn get_field_argument_definition(&self) -> darling::Result<TokenStream> {
@@ -243,7 +219,7 @@ where
     let fields = object
         .get_fields()?
         .iter()
-        .filter(|field| !field.get_skip())
+        .filter(|field| !field.get_skip() && !field.get_flatten())
         .map(impl_resolver)
         .map(|r| r.into_token_stream())
         .collect::<Vec<TokenStream>>();
@@ -255,6 +231,252 @@ where
     })
 }
 
+fn check_fields(object: &SimpleObject) -> darling::Result<()> {
+    let mut errors = darling::Error::accumulator();
+    if object.attrs.input_name.is_none() {
+        let attrs = &object.attrs;
+        let input_attrs = [
+            ("collect_errors", attrs.collect_errors),
+            ("patch_for", attrs.patch_for.is_some()),
+        ];
+        for (name, _) in input_attrs.iter().filter(|(_, is_set)| *is_set) {
+            errors.push(
+                darling::Error::custom(format!(
+                    "`{}` is an InputObject attribute, it requires `input_name`",
+                    name
+                ))
+                .with_span(&object.ident),
+            );
+        }
+        for field in object
+            .data
+            .fields
+            .iter()
+            .filter(|field| field.attrs.input.is_set() || field.attrs.default.is_set())
+        {
+            errors.push(
+                darling::Error::custom(
+                    "InputObject field attributes require `input_name` on the type",
+                )
+                .with_span(&field.ident),
+            );
+        }
+    }
+    for field in object
+        .data
+        .fields
+        .iter()
+        .filter(|field| field.attrs.flatten)
+    {
+        let attrs = &field.attrs;
+        let conflict = if attrs.skip {
+            Some("skip")
+        } else if attrs.name.is_some() {
+            Some("name")
+        } else if !matches!(attrs.deprecation, Deprecation::NoDeprecated) {
+            Some("deprecation")
+        } else if attrs.partial_list {
+            Some("partial_list")
+        } else if attrs.with.is_some() {
+            Some("with")
+        } else {
+            None
+        };
+        if let Some(conflict) = conflict {
+            errors.push(
+                darling::Error::custom(format!("`flatten` can't be used with `{}`", conflict))
+                    .with_span(&field.ident),
+            );
+        }
+    }
+    errors.finish()
+}
+
+/// the names of the computed fields are used verbatim, so they are known here
+fn check_computed_fields(object: &SimpleObject) -> darling::Result<()> {
+    let mut errors = darling::Error::accumulator();
+    let mut names = object
+        .data
+        .fields
+        .iter()
+        .filter(|field| !field.get_skip() && !field.get_flatten())
+        .map(common::get_field_name)
+        .collect::<darling::Result<Vec<_>>>()?;
+    for computed in &object.attrs.computed {
+        if names.contains(&computed.name) {
+            errors.push(
+                darling::Error::custom(format!("Duplicate field \"{}\"", computed.name))
+                    .with_span(&computed.with),
+            );
+        }
+        names.push(computed.name.clone());
+    }
+    errors.finish()
+}
+
+/// the names of the flattened objects are only known by their `FlattenObject`, they are checked
+/// at compile time
+fn get_check_field_names_code(object: &SimpleObject) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let mut names = object
+        .data
+        .fields
+        .iter()
+        .filter(|field| !field.get_skip() && !field.get_flatten())
+        .map(|field| Ok((common::get_field_name(field)?, field.ident.span())))
+        .collect::<darling::Result<Vec<_>>>()?;
+    names.extend(
+        object
+            .attrs
+            .computed
+            .iter()
+            .map(|computed| (computed.name.clone(), computed.with.span())),
+    );
+    let flattened = get_flattened_fields(object)
+        .map(|field| (&field.ty, field.ident.span()))
+        .collect::<Vec<_>>();
+    Ok(common::get_check_flattened_names_code(
+        &object.ident,
+        &names,
+        &flattened,
+        quote!(#crate_name::internal::FlattenObject),
+    ))
+}
+
+fn get_flattened_fields(object: &SimpleObject) -> impl Iterator<Item = &SimpleObjectField> {
+    object
+        .data
+        .fields
+        .iter()
+        .filter(|field| !field.get_skip() && field.get_flatten())
+}
+
+fn get_computed_register_code(object: &SimpleObject) -> TokenStream {
+    let types = object.attrs.computed.iter().map(|computed| &computed.ty);
+    quote! {
+        #( let registry = registry.register::<#types>(); )*
+    }
+}
+
+/// the computed fields resolve the parent with `__get` too, so they can be flattened
+fn get_define_computed_fields_code(object: &SimpleObject) -> TokenStream {
+    let crate_name = get_crate_name();
+    let fields = object.attrs.computed.iter().map(|computed| {
+        let ComputedAttr { name, ty, with } = computed;
+        quote! {
+            let field = #crate_name::dynamic::Field::new(#name, <#ty as #crate_name::internal::GetOutputTypeRef>::get_output_type_ref(), move |ctx| {
+                #crate_name::dynamic::FieldFuture::new(async move {
+                    let parent = __get(ctx.parent_value.try_downcast_ref::<__P>()?);
+                    let value: #ty = #with(parent);
+                    #crate_name::internal::Resolve::resolve(value, &ctx)
+                })
+            });
+            let object = object.field(field);
+        }
+    });
+    quote! {
+        #(#fields)*
+    }
+}
+
+fn get_computed_signature_code(object: &SimpleObject) -> TokenStream {
+    let crate_name = get_crate_name();
+    let fields = object.attrs.computed.iter().map(|computed| {
+        let ComputedAttr { name, ty, .. } = computed;
+        quote! {
+            let signature = signature.field(#crate_name::internal::FieldSignature::new(
+                #name,
+                <#ty as #crate_name::internal::GetOutputTypeRef>::get_output_type_ref(),
+            ));
+        }
+    });
+    quote! {
+        #(#fields)*
+    }
+}
+
+/// the fields of the object are defined by `FlattenObject`, so they can be flattened into other objects
+fn impl_flatten_object(object: &SimpleObject) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = &object.ident;
+
+    let register_nested_types = common::get_nested_type_register_code(object).into_token_stream();
+    let define_fields = common::get_define_fields_code(object)?;
+    let fields_signature = common::get_fields_signature_code(object)?;
+    let field_names = object
+        .data
+        .fields
+        .iter()
+        .filter(|field| !field.get_skip() && !field.get_flatten())
+        .map(common::get_field_name)
+        .collect::<darling::Result<Vec<_>>>()?;
+
+    let register_computed = get_computed_register_code(object);
+    let define_computed = get_define_computed_fields_code(object);
+    let computed_signature = get_computed_signature_code(object);
+    let computed_names = object.attrs.computed.iter().map(|computed| &computed.name);
+
+    let mut register_flattened = Vec::new();
+    let mut define_flattened = Vec::new();
+    let mut flattened_signature = Vec::new();
+    let mut flattened_names = Vec::new();
+    for field in get_flattened_fields(object) {
+        let field_ident = &field.ident;
+        let ty = &field.ty;
+        register_flattened.push(quote! {
+            let registry = <#ty as #crate_name::internal::FlattenObject>::register_fields(registry);
+        });
+        define_flattened.push(quote! {
+            let object = <#ty as #crate_name::internal::FlattenObject>::define_fields::<__P, _>(
+                object,
+                move |parent: &__P| &__get(parent).#field_ident,
+            );
+        });
+        flattened_signature.push(quote! {
+            let signature = <#ty as #crate_name::internal::FlattenObject>::fields_signature(signature);
+        });
+        flattened_names.push(quote! {
+            <#ty as #crate_name::internal::FlattenObject>::FIELD_NAMES
+        });
+    }
+
+    let (impl_generics, ty_generics, where_clause) = object.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #crate_name::internal::FlattenObject for #ident #ty_generics #where_clause {
+            fn register_fields(registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry {
+                #register_nested_types
+                #register_computed
+                #(#register_flattened)*
+                registry
+            }
+
+            fn define_fields<__P, __G>(object: #crate_name::dynamic::Object, __get: __G) -> #crate_name::dynamic::Object
+            where
+                __P: 'static,
+                __G: Fn(&__P) -> &Self + Copy + Send + Sync + 'static,
+            {
+                #define_fields
+                #define_computed
+                #(#define_flattened)*
+                object
+            }
+
+            fn fields_signature(signature: #crate_name::internal::TypeSignature) -> #crate_name::internal::TypeSignature {
+                #fields_signature
+                #computed_signature
+                #(#flattened_signature)*
+                signature
+            }
+
+            const FIELD_NAMES: #crate_name::internal::FieldNames = #crate_name::internal::FieldNames {
+                names: &[#(#field_names,)* #(#computed_names),*],
+                flattened: &[#(#flattened_names),*],
+            };
+        }
+    })
+}
+
 fn root_register_code(object: &SimpleObject) -> TokenStream {
     let root = if object.attrs.root {
         let crate_name = get_crate_name();
@@ -278,7 +500,6 @@ fn root_register_code(object: &SimpleObject) -> TokenStream {
 fn impl_register(object: &SimpleObject) -> darling::Result<TokenStream> {
     let crate_name = get_crate_name();
 
-    let register_nested_types = common::get_nested_type_register_code(object).into_token_stream();
     let root_register = root_register_code(object);
 
     let ident = &object.ident;
@@ -286,13 +507,80 @@ fn impl_register(object: &SimpleObject) -> darling::Result<TokenStream> {
     let add_interfaces = common::get_interface_mark_code(object)?;
     let register_interface_code = get_register_interface_code(object)?;
     let implement = common::get_add_implement_code(object, object.get_impls())?;
+    let interface_defaults = common::get_interface_default_code(object)?;
 
     let description = common::object_description(object.get_doc()?.as_deref())?;
-    let define_fields = common::get_define_fields_code(object)?;
+    let mark_signature = common::get_interface_mark_signature_code(object)?;
+    // the signature is only needed to check the marked interfaces
+    let add_signature = (!object.attrs.marks.is_empty()).then(|| {
+        common::add_signature_code(
+            quote!(<Self as #crate_name::internal::Object>::get_object_type_name()),
+            quote! {
+                let signature = signature.rust_type(std::any::type_name::<Self>());
+                #mark_signature
+                let signature = <Self as #crate_name::internal::FlattenObject>::fields_signature(signature);
+            },
+        )
+    });
+    let check_field_names = get_check_field_names_code(object)?;
     let register_object_code = common::register_object_code();
 
     let (impl_generics, ty_generics, where_clause) = object.generics.split_for_impl();
     let register_attr = &object.attrs.registers;
+    let extends_unions = common::get_extends_union_code(&object.attrs.extends_unions);
+    let register_input_object = object.attrs.input_name.as_ref().map(|_| {
+        quote! {
+            let registry = <Self as #crate_name::internal::RegisterInputObject>::register_input_object(registry);
+        }
+    });
+
+    if object.attrs.complex {
+        let turbofish_generics = ty_generics.as_turbofish();
+        // the fields are added to the object defined by `ResolvedObjectFields`, which rejects the
+        // duplicate fields with `RegisterFns::FIELD_NAMES` at compile time
+        return Ok(quote! {
+            impl #impl_generics #ident #ty_generics #where_clause {
+                fn __register_simple_object(registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry {
+
+                    #( #register_attr )*
+
+                    #register_input_object
+
+                    #register_interface_code
+
+                    let registry = <Self as #crate_name::internal::FlattenObject>::register_fields(registry);
+
+                    #root_register
+
+                    #implement
+
+                    #interface_defaults
+
+                    #extends_unions
+
+                    #check_field_names
+
+                    #add_signature
+
+                    registry.update_object(
+                        <Self as #crate_name::internal::Object>::get_object_type_name().as_ref(),
+                        <Self as #crate_name::internal::Object>::get_object_type_name().as_ref(),
+                        |object| {
+                            #add_interfaces
+                            #description
+                            <Self as #crate_name::internal::FlattenObject>::define_fields::<Self, _>(object, |parent: &Self| parent)
+                        },
+                    )
+                }
+            }
+            impl #impl_generics #crate_name::internal::RegisterFns for #ident #ty_generics #where_clause {
+                const REGISTER_FNS: &'static [fn (registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry] = &[
+                    #ident #turbofish_generics ::__register_simple_object,
+                ];
+                const FIELD_NAMES: #crate_name::internal::FieldNames = <Self as #crate_name::internal::FlattenObject>::FIELD_NAMES;
+            }
+        });
+    }
 
     Ok(quote! {
         impl #impl_generics #crate_name::internal::Register for #ident #ty_generics #where_clause {
@@ -300,9 +588,11 @@ fn impl_register(object: &SimpleObject) -> darling::Result<TokenStream> {
 
                 #( #register_attr )*
 
+                #register_input_object
+
                 #register_interface_code
 
-                #register_nested_types
+                let registry = <Self as #crate_name::internal::FlattenObject>::register_fields(registry);
 
                 #root_register
 
@@ -310,11 +600,19 @@ fn impl_register(object: &SimpleObject) -> darling::Result<TokenStream> {
 
                 #implement
 
+                #interface_defaults
+
+                #extends_unions
+
                 #add_interfaces
 
                 #description
 
-                #define_fields
+                #check_field_names
+
+                let object = <Self as #crate_name::internal::FlattenObject>::define_fields::<Self, _>(object, |parent: &Self| parent);
+
+                #add_signature
 
                 #register_object_code
             }
@@ -324,11 +622,19 @@ fn impl_register(object: &SimpleObject) -> darling::Result<TokenStream> {
 
 impl ToTokens for SimpleObject {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        let mut errors = darling::Error::accumulator();
+        errors.handle(check_fields(self));
+        errors.handle(check_computed_fields(self));
+        if let Err(err) = errors.finish() {
+            tokens.extend(err.write_errors());
+            return;
+        }
         let impl_object = common::impl_object(self).into_token_stream();
         let impl_resolve_owned = common::impl_resolve_owned(self).into_token_stream();
         let impl_resolve_ref = common::impl_resolve_ref(self).into_token_stream();
         let impl_resolvers = impl_resolvers(self).into_token_stream();
         let impl_register = impl_register(self).into_token_stream();
+        let impl_flatten_object = impl_flatten_object(self).into_token_stream();
         let impl_interface_mark = common::impl_interface_mark(self).into_token_stream();
 
         tokens.extend(quote! {
@@ -338,6 +644,7 @@ impl ToTokens for SimpleObject {
             #impl_resolve_ref
             #impl_resolvers
             #impl_register
+            #impl_flatten_object
         })
     }
 }
@@ -211,6 +211,41 @@ fn test_simple_object() {
     insta::assert_snapshot!(output);
 }
 
+#[test]
+fn test_graphql_error() {
+    let input = r#"
+        enum UserError {
+            /// the user does not exist
+            #[graphql(message = "User {id} not found")]
+            NotFound { id: String },
+            #[graphql(code = "FORBIDDEN", message = "Permission denied")]
+            PermissionDenied {
+                role: String,
+                #[graphql(skip)]
+                reason: String,
+            },
+            #[graphql(message = "Internal error: {0}")]
+            Internal(String),
+        }
+    "#;
+
+    let pretty = pretty_derive::<GraphQLError>(input);
+    let output = md(&[&pretty]);
+    insta::assert_snapshot!(output);
+}
+
+#[test]
+fn test_transparent() {
+    let input = r#"
+        #[graphql(validator(validate_email))]
+        struct Email(String);
+    "#;
+
+    let pretty = pretty_derive::<Transparent>(input);
+    let output = md(&[&pretty]);
+    insta::assert_snapshot!(output);
+}
+
 #[test]
 fn test_union() {
     let input = r#"
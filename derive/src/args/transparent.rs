@@ -0,0 +1,222 @@
+use darling::FromAttributes;
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use quote::quote;
+use syn::Generics;
+
+use crate::args::common::add_new_lifetime_to_generics;
+use crate::args::common::replace_type_generics_with_static;
+use crate::utils::crate_name::get_crate_name;
+use crate::utils::derive_types::NewtypeStruct;
+use crate::utils::derive_types::TupleField;
+use crate::utils::error::IntoTokenStream;
+use crate::utils::macros::*;
+use crate::utils::path_attr::PathAttr;
+use crate::utils::with_attributes::WithAttributes;
+
+#[derive(FromAttributes, Debug, Clone)]
+#[darling(attributes(graphql))]
+pub struct TransparentAttrs {
+    #[darling(default)]
+    pub validator: Option<PathAttr>,
+
+    /// only implement the input traits, for an input only inner type, e.g. an input object
+    #[darling(default)]
+    pub input: bool,
+
+    /// only implement the output traits, for an output only inner type, e.g. an object
+    #[darling(default)]
+    pub output: bool,
+}
+
+from_derive_input!(
+    Transparent,
+    WithAttributes<TransparentAttrs, NewtypeStruct<TupleField, Generics>>,
+);
+
+impl Transparent {
+    /// both directions are implemented unless only one of them is set
+    fn is_input(&self) -> bool {
+        self.attrs.input || !self.attrs.output
+    }
+
+    fn is_output(&self) -> bool {
+        self.attrs.output || !self.attrs.input
+    }
+}
+
+fn check_attrs(object: &Transparent) -> darling::Result<()> {
+    match &object.attrs.validator {
+        Some(validator) if !object.is_input() => Err(darling::Error::custom(
+            "`validator` is called on input, it can't be used with `output`",
+        )
+        .with_span(&validator.0)),
+        _ => Ok(()),
+    }
+}
+
+fn impl_type_name(object: &Transparent) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = &object.ident;
+    let inner = &object.data.ty;
+    let (impl_generics, ty_generics, where_clause) = object.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #crate_name::internal::TypeName for #ident #ty_generics #where_clause {
+            fn get_type_name() -> std::borrow::Cow<'static, str> {
+                <#inner as #crate_name::internal::TypeName>::get_type_name()
+            }
+        }
+    })
+}
+
+fn impl_input_type_name(object: &Transparent) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = &object.ident;
+    let inner = &object.data.ty;
+    let (impl_generics, ty_generics, where_clause) = object.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #crate_name::internal::InputTypeName for #ident #ty_generics #where_clause {
+            fn get_input_type_name() -> std::borrow::Cow<'static, str> {
+                <#inner as #crate_name::internal::InputTypeName>::get_input_type_name()
+            }
+        }
+    })
+}
+
+fn impl_output_type_name(object: &Transparent) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = &object.ident;
+    let inner = &object.data.ty;
+    let (impl_generics, ty_generics, where_clause) = object.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #crate_name::internal::OutputTypeName for #ident #ty_generics #where_clause {
+            fn get_output_type_name() -> std::borrow::Cow<'static, str> {
+                <#inner as #crate_name::internal::OutputTypeName>::get_output_type_name()
+            }
+        }
+    })
+}
+
+fn impl_register(object: &Transparent) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = &object.ident;
+    let inner = replace_type_generics_with_static(&object.data.ty);
+    let (impl_generics, ty_generics, where_clause) = object.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #crate_name::internal::Register for #ident #ty_generics #where_clause {
+            fn register(registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry {
+                registry.register::<#inner>()
+            }
+        }
+    })
+}
+
+fn impl_resolve_owned(object: &Transparent) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = &object.ident;
+    let (_, ty_generics, where_clause) = object.generics.split_for_impl();
+    let (generics_with_lifetime, lifetime) = add_new_lifetime_to_generics(&object.generics);
+    let (impl_generics, _, _) = generics_with_lifetime.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #crate_name::internal::ResolveOwned<#lifetime> for #ident #ty_generics #where_clause {
+            fn resolve_owned(self, ctx: &#crate_name::Context) -> #crate_name::Result<Option<#crate_name::FieldValue<#lifetime>>> {
+                #crate_name::internal::Resolve::resolve(self.0, ctx)
+            }
+        }
+    })
+}
+
+fn impl_resolve_ref(object: &Transparent) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = &object.ident;
+    let (_, ty_generics, where_clause) = object.generics.split_for_impl();
+    let (generics_with_lifetime, lifetime) = add_new_lifetime_to_generics(&object.generics);
+    let (impl_generics, _, _) = generics_with_lifetime.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #crate_name::internal::ResolveRef<#lifetime> for #ident #ty_generics #where_clause {
+            fn resolve_ref(&#lifetime self, ctx: &#crate_name::Context) -> #crate_name::Result<Option<#crate_name::FieldValue<#lifetime>>> {
+                #crate_name::internal::Resolve::resolve(&self.0, ctx)
+            }
+        }
+    })
+}
+
+fn get_validator_code(object: &Transparent) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    Ok(match &object.attrs.validator {
+        Some(validator) => {
+            let path = &validator.0;
+            quote! {
+                #path(&value).map_err(#crate_name::internal::InputValueError::custom)?;
+            }
+        }
+        None => quote!(),
+    })
+}
+
+fn impl_from_value(object: &Transparent) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = &object.ident;
+    let inner = &object.data.ty;
+    let validator_code = get_validator_code(object)?;
+    let (impl_generics, ty_generics, where_clause) = object.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #crate_name::internal::FromValue for #ident #ty_generics #where_clause {
+            fn from_value(value: #crate_name::Result<#crate_name::dynamic::ValueAccessor>) -> #crate_name::internal::InputValueResult<Self> {
+                let value = <#inner as #crate_name::internal::FromValue>::from_value(value).map_err(#crate_name::internal::InputValueError::propagate)?;
+                #validator_code
+                Ok(Self(value))
+            }
+        }
+    })
+}
+
+fn impl_to_input_value(object: &Transparent) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = &object.ident;
+    let inner = &object.data.ty;
+    let (impl_generics, ty_generics, where_clause) = object.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #crate_name::internal::ToInputValue for #ident #ty_generics #where_clause {
+            fn to_input_value(&self) -> #crate_name::Value {
+                <#inner as #crate_name::internal::ToInputValue>::to_input_value(&self.0)
+            }
+        }
+    })
+}
+
+impl ToTokens for Transparent {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if let Err(err) = check_attrs(self) {
+            tokens.extend(err.write_errors());
+            return;
+        }
+        let (input, output) = (self.is_input(), self.is_output());
+        let impl_type_name = impl_type_name(self).into_token_stream();
+        let impl_input_type_name = input.then(|| impl_input_type_name(self).into_token_stream());
+        let impl_output_type_name = output.then(|| impl_output_type_name(self).into_token_stream());
+        let impl_register = impl_register(self).into_token_stream();
+        let impl_resolve_owned = output.then(|| impl_resolve_owned(self).into_token_stream());
+        let impl_resolve_ref = output.then(|| impl_resolve_ref(self).into_token_stream());
+        let impl_from_value = input.then(|| impl_from_value(self).into_token_stream());
+        let impl_to_input_value = input.then(|| impl_to_input_value(self).into_token_stream());
+        tokens.extend(quote! {
+            #impl_type_name
+            #impl_input_type_name
+            #impl_output_type_name
+            #impl_register
+            #impl_resolve_owned
+            #impl_resolve_ref
+            #impl_from_value
+            #impl_to_input_value
+        });
+    }
+}
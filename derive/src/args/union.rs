@@ -1,4 +1,4 @@
-use darling::FromAttributes;
+use darling::ast::Style;
 use proc_macro2::Ident;
 use proc_macro2::TokenStream;
 use quote::ToTokens;
@@ -11,42 +11,54 @@ use crate::args::common;
 use crate::args::common::add_new_lifetime_to_generics;
 use crate::utils::common::CommonField;
 use crate::utils::common::CommonObject;
-use crate::utils::common::EMPTY_ARGS;
-use crate::utils::common::GetArgs;
 use crate::utils::common::GetFields;
 use crate::utils::crate_name::get_crate_name;
+use crate::utils::deprecation::Deprecation;
 use crate::utils::derive_types::BaseEnum;
-use crate::utils::derive_types::NewtypeVariant;
+use crate::utils::derive_types::BaseField;
+use crate::utils::derive_types::BaseVariant;
 use crate::utils::error::IntoTokenStream;
 use crate::utils::macros::*;
-use crate::utils::register_attr::RegisterAttr;
+use crate::utils::paired_attrs::ObjectFieldAttrs;
+use crate::utils::paired_attrs::UnionAttrs;
+use crate::utils::paired_attrs::UnionVariantAttrs;
+use crate::utils::rename_rule::RenameRule;
 use crate::utils::type_utils::get_owned_type;
 use crate::utils::type_utils::get_type_path;
 use crate::utils::with_attributes::WithAttributes;
+use crate::utils::with_context::MakeContext;
+use crate::utils::with_context::WithContext;
 use crate::utils::with_doc::WithDoc;
 
-from_variant!(UnionItem, NewtypeVariant,);
-
-#[derive(FromAttributes, Debug, Clone)]
-#[darling(attributes(graphql))]
-pub struct UnionAttrs {
-    #[darling(default)]
-    name: Option<String>,
+#[derive(Default, Debug, Clone)]
+pub struct UnionItemFieldContext {
+    pub rename_fields: Option<RenameRule>,
+}
 
-    #[darling(default)]
-    #[darling(rename = "get_type_name")]
-    pub type_name: bool,
+from_field!(
+    UnionItemField,
+    WithAttributes<WithDoc<ObjectFieldAttrs>, WithContext<UnionItemFieldContext, BaseField>>,
+);
 
-    #[darling(default, multiple)]
-    #[darling(rename = "register")]
-    pub registers: Vec<RegisterAttr>,
-}
+from_variant!(
+    UnionItem,
+    WithAttributes<WithDoc<UnionVariantAttrs>, BaseVariant<UnionItemField>>,
+);
 
 from_derive_input!(
     Union,
     WithAttributes<WithDoc<UnionAttrs>, BaseEnum<UnionItem, Generics>>,
+    ctx,
 );
 
+impl MakeContext<UnionItemFieldContext> for Union {
+    fn make_context(&self) -> UnionItemFieldContext {
+        UnionItemFieldContext {
+            rename_fields: self.attrs.rename_fields,
+        }
+    }
+}
+
 impl CommonObject for Union {
     fn get_name(&self) -> Option<&str> {
         self.attrs.name.as_deref()
@@ -79,9 +91,17 @@ impl GetFields<UnionItem> for Union {
     }
 }
 
-impl GetArgs<()> for UnionItem {
-    fn get_args(&self) -> darling::Result<&Vec<()>> {
-        Ok(&EMPTY_ARGS)
+impl UnionItem {
+    /// the type of the newtype variants, other variants are resolved as their own object
+    fn get_newtype(&self) -> Option<&Type> {
+        match self.fields.style {
+            Style::Tuple => self.fields.fields.first().map(|field| &field.ty),
+            _ => None,
+        }
+    }
+
+    fn get_object_fields(&self) -> impl Iterator<Item = &UnionItemField> {
+        self.fields.iter().filter(|field| !field.get_skip())
     }
 }
 
@@ -95,7 +115,9 @@ impl CommonField for UnionItem {
     }
 
     fn get_type(&self) -> darling::Result<&Type> {
-        Ok(&self.fields.ty)
+        self.get_newtype().ok_or_else(|| {
+            darling::Error::custom("Union variant has no type").with_span(&self.ident)
+        })
     }
 
     fn get_skip(&self) -> bool {
@@ -103,7 +125,127 @@ impl CommonField for UnionItem {
     }
 
     fn get_doc(&self) -> darling::Result<Option<String>> {
-        Ok(None)
+        Ok(self.attrs.doc.clone())
+    }
+}
+
+impl CommonField for UnionItemField {
+    fn get_name(&self) -> Option<&str> {
+        self.attrs
+            .output
+            .name
+            .as_deref()
+            .or(self.attrs.name.as_deref())
+    }
+
+    fn get_ident(&self) -> darling::Result<&Ident> {
+        self.ident
+            .as_ref()
+            .ok_or_else(|| darling::Error::custom("Field has no name").with_span(&self.ty))
+    }
+
+    fn get_type(&self) -> darling::Result<&Type> {
+        Ok(&self.ty)
+    }
+
+    fn get_skip(&self) -> bool {
+        self.attrs.skip || self.attrs.output.skip
+    }
+
+    fn get_doc(&self) -> darling::Result<Option<String>> {
+        Ok(self.attrs.doc.clone())
+    }
+
+    fn get_deprecation(&self) -> darling::Result<Deprecation> {
+        Ok(self.attrs.deprecation.clone())
+    }
+
+    fn get_field_rename_rule(&self) -> Option<&RenameRule> {
+        self.ctx.rename_fields.as_ref()
+    }
+}
+
+fn check_items(union: &Union) -> darling::Result<()> {
+    let mut errors = darling::Error::accumulator();
+    for item in &union.data {
+        if item.fields.style == Style::Tuple && item.fields.len() != 1 {
+            errors.push(darling::Error::unsupported_shape("tuple variant").with_span(&item.ident));
+        }
+        for field in item.fields.iter() {
+            let attrs = &field.attrs;
+            let unsupported = if attrs.flatten {
+                Some("flatten")
+            } else if attrs.partial_list {
+                Some("partial_list")
+            } else if attrs.with.is_some() {
+                Some("with")
+            } else {
+                None
+            };
+            let Some(unsupported) = unsupported else {
+                continue;
+            };
+            let error =
+                darling::Error::custom(format!("`{}` is not supported in variants", unsupported));
+            match &field.ident {
+                Some(ident) => errors.push(error.with_span(ident)),
+                None => errors.push(error.with_span(&item.ident)),
+            }
+        }
+        if union.attrs.input_name.is_some() {
+            continue;
+        }
+        let attrs = &item.attrs;
+        let input_attrs = [
+            ("name", attrs.name.is_some()),
+            ("skip", attrs.skip),
+            (
+                "deprecation",
+                !matches!(attrs.deprecation, Deprecation::NoDeprecated),
+            ),
+        ];
+        for (name, _) in input_attrs.iter().filter(|(_, is_set)| *is_set) {
+            errors.push(
+                darling::Error::custom(format!(
+                    "`{}` is a OneOfInput attribute, it requires `input_name`",
+                    name
+                ))
+                .with_span(&item.ident),
+            );
+        }
+        for field in item.fields.iter() {
+            if field.attrs.input.is_set()
+                || field.attrs.output.is_set()
+                || field.attrs.default.is_set()
+            {
+                let error = darling::Error::custom(
+                    "OneOfInput field attributes require `input_name` on the type",
+                );
+                match &field.ident {
+                    Some(ident) => errors.push(error.with_span(ident)),
+                    None => errors.push(error.with_span(&item.ident)),
+                }
+            }
+        }
+    }
+    errors.finish()
+}
+
+/// the name of the object of struct and unit variants, `{Union}{Variant}` by default
+fn get_item_type_name_code(item: &UnionItem) -> TokenStream {
+    let crate_name = get_crate_name();
+    match &item.attrs.type_name {
+        Some(name) => quote!(std::borrow::Cow::<'static, str>::Borrowed(#name)),
+        None => {
+            let variant_name = item.ident.to_string();
+            quote! {
+                std::borrow::Cow::<'static, str>::Owned(format!(
+                    "{}{}",
+                    <Self as #crate_name::internal::Union>::get_union_type_name(),
+                    #variant_name,
+                ))
+            }
+        }
     }
 }
 
@@ -113,6 +255,21 @@ fn impl_union(union: &Union) -> darling::Result<TokenStream> {
     let ident = union.get_ident();
     let (impl_generics, ty_generics, where_clause) = union.get_generics()?.split_for_impl();
 
+    let possible_types = union
+        .data
+        .iter()
+        .map(|item| match item.get_newtype() {
+            Some(ty) => {
+                let ty = get_owned_type(ty);
+                quote!(types.extend(<#ty as #crate_name::internal::UnionMember>::get_union_member_types());)
+            }
+            None => {
+                let name = get_item_type_name_code(item);
+                quote!(types.push(#name);)
+            }
+        })
+        .collect::<Vec<_>>();
+
     let type_name = union.should_impl_type_name().then_some(quote! {
         impl #impl_generics #crate_name::internal::TypeName for #ident #ty_generics #where_clause {
             fn get_type_name() -> std::borrow::Cow<'static, str> {
@@ -124,6 +281,13 @@ fn impl_union(union: &Union) -> darling::Result<TokenStream> {
         #type_name
         impl #impl_generics #crate_name::internal::OutputTypeName for #ident #ty_generics #where_clause {}
         impl #impl_generics #crate_name::internal::Union for #ident #ty_generics #where_clause {}
+        impl #impl_generics #crate_name::internal::UnionMember for #ident #ty_generics #where_clause {
+            fn get_union_member_types() -> Vec<std::borrow::Cow<'static, str>> {
+                let mut types = Vec::new();
+                #( #possible_types )*
+                types
+            }
+        }
     })
 }
 
@@ -134,10 +298,18 @@ fn define_resolve_owned_match_pattern(
     let crate_name = get_crate_name();
     let union_ident = union.get_ident();
     let variant_ident = &item.ident;
-    let variant_type = get_type_path(&item.fields.ty)?;
+    let Some(variant_type) = item.get_newtype() else {
+        let name = get_item_type_name_code(item);
+        return Ok(quote! {
+            #union_ident::#variant_ident { .. } => {
+                Ok(Some(#crate_name::FieldValue::owned_any(self).with_type(#name)))
+            }
+        });
+    };
+    let variant_type = get_type_path(variant_type)?;
     Ok(quote! {
         #union_ident::#variant_ident(value) => {
-            #crate_name::internal::Resolve::resolve(value,ctx).map(|value| value.map(|value| value.with_type(<#variant_type as #crate_name::internal::Object>::get_object_type_name())))
+            #crate_name::internal::Resolve::resolve(value,ctx).map(|value| value.map(<#variant_type as #crate_name::internal::UnionMember>::with_union_member_type))
         }
     })
 }
@@ -172,10 +344,18 @@ fn define_resolve_ref_match_pattern(
     let crate_name = get_crate_name();
     let union_ident = union.get_ident();
     let variant_ident = &item.ident;
-    let variant_type = get_type_path(&item.fields.ty)?;
+    let Some(variant_type) = item.get_newtype() else {
+        let name = get_item_type_name_code(item);
+        return Ok(quote! {
+            #union_ident::#variant_ident { .. } => {
+                Ok(Some(#crate_name::FieldValue::borrowed_any(self).with_type(#name)))
+            }
+        });
+    };
+    let variant_type = get_type_path(variant_type)?;
     Ok(quote! {
         #union_ident::#variant_ident(value) => {
-            #crate_name::internal::Resolve::resolve(value,ctx).map(|value| value.map(|value| value.with_type(<#variant_type as #crate_name::internal::Object>::get_object_type_name())))
+            #crate_name::internal::Resolve::resolve(value,ctx).map(|value| value.map(<#variant_type as #crate_name::internal::UnionMember>::with_union_member_type))
         }
     })
 }
@@ -210,11 +390,89 @@ fn define_union_code() -> darling::Result<TokenStream> {
     })
 }
 
+fn define_item_object_field(
+    item: &UnionItem,
+    field: &UnionItemField,
+) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let variant_ident = &item.ident;
+    let field_ident = field.get_ident()?;
+    let field_name = common::get_field_name(field)?;
+    let field_type_ref = common::get_field_type_ref_code(field)?;
+    let resolve = common::resolve_value_code(field)?;
+    let description = common::field_description(field)?;
+    let deprecation = common::field_deprecation_code(field)?;
+    Ok(quote! {
+        let field = #crate_name::dynamic::Field::new(#field_name, #field_type_ref, |ctx| {
+            #crate_name::dynamic::FieldFuture::new(async move {
+                let parent = ctx.parent_value.try_downcast_ref::<Self>()?;
+                #[allow(unreachable_patterns)]
+                let value = match parent {
+                    Self::#variant_ident { #field_ident: value, .. } => value,
+                    _ => return Err(#crate_name::Error::new("Unexpected union variant")),
+                };
+                #resolve
+            })
+        });
+        #description
+        #deprecation
+        let object = object.field(field);
+    })
+}
+
+/// objects need at least one field, so the unit variants get a `_: Boolean!` field
+fn define_placeholder_field() -> TokenStream {
+    let crate_name = get_crate_name();
+    quote! {
+        let field = #crate_name::dynamic::Field::new(
+            "_",
+            #crate_name::dynamic::TypeRef::named_nn(#crate_name::dynamic::TypeRef::BOOLEAN),
+            |_| #crate_name::dynamic::FieldFuture::new(async move {
+                Ok(Some(#crate_name::FieldValue::value(true)))
+            }),
+        );
+        let object = object.field(field);
+    }
+}
+
+fn define_item_object(item: &UnionItem) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let name = get_item_type_name_code(item);
+    let description = common::object_description(item.attrs.doc.as_deref())?;
+    let fields = item
+        .get_object_fields()
+        .map(|field| define_item_object_field(item, field).into_token_stream())
+        .collect::<Vec<_>>();
+    let fields = if fields.is_empty() {
+        define_placeholder_field()
+    } else {
+        quote!(#(#fields)*)
+    };
+    Ok(quote! {
+        let registry = {
+            let name = #name;
+            let object = #crate_name::dynamic::Object::new(name.as_ref());
+            #description
+            #fields
+            registry.register_type(object).update_union(
+                <Self as #crate_name::internal::Union>::get_union_type_name().as_ref(),
+                name.as_ref(),
+            )
+        };
+    })
+}
+
 fn define_item(item: &UnionItem) -> darling::Result<TokenStream> {
     let crate_name = get_crate_name();
-    let ty = get_owned_type(&item.fields.ty);
+    let Some(ty) = item.get_newtype() else {
+        return define_item_object(item);
+    };
+    let ty = get_owned_type(ty);
     Ok(quote! {
-        let object = object.possible_type(<#ty as #crate_name::internal::Object>::get_object_type_name().as_ref());
+        let registry = registry.update_union(
+            <Self as #crate_name::internal::Union>::get_union_type_name().as_ref(),
+            <#ty as #crate_name::internal::OutputTypeName>::get_output_type_name().as_ref(),
+        );
     })
 }
 
@@ -229,10 +487,26 @@ fn define_items(union: &Union) -> darling::Result<TokenStream> {
     })
 }
 
+fn get_nested_type_register_code(union: &Union) -> darling::Result<TokenStream> {
+    let mut types: Vec<&Type> = Vec::new();
+    for item in &union.data {
+        let item_types: Vec<&Type> = match item.get_newtype() {
+            Some(ty) => vec![ty],
+            None => item.get_object_fields().map(|field| &field.ty).collect(),
+        };
+        for ty in item_types {
+            if !types.contains(&ty) {
+                types.push(ty);
+            }
+        }
+    }
+    Ok(common::get_types_register_code(&types))
+}
+
 fn impl_register(union: &Union) -> darling::Result<TokenStream> {
     let crate_name = get_crate_name();
     let ident = union.get_ident();
-    let register_nested_types = common::get_nested_type_register_code(union).into_token_stream();
+    let register_nested_types = get_nested_type_register_code(union).into_token_stream();
 
     let define_union = define_union_code().into_token_stream();
     let description = union
@@ -242,6 +516,11 @@ fn impl_register(union: &Union) -> darling::Result<TokenStream> {
     let define_items = define_items(union).into_token_stream();
     let register_union = common::register_object_code().into_token_stream();
     let register_attr = &union.attrs.registers;
+    let register_input_object = union.attrs.input_name.as_ref().map(|_| {
+        quote! {
+            let registry = <Self as #crate_name::internal::RegisterInputObject>::register_input_object(registry);
+        }
+    });
     let (impl_generics, ty_generics, where_clause) = union.generics.split_for_impl();
 
     Ok(quote! {
@@ -250,6 +529,8 @@ fn impl_register(union: &Union) -> darling::Result<TokenStream> {
 
                 #( #register_attr )*
 
+                #register_input_object
+
                 #register_nested_types
 
                 #define_union
@@ -266,6 +547,10 @@ fn impl_register(union: &Union) -> darling::Result<TokenStream> {
 
 impl ToTokens for Union {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        if let Err(err) = check_items(self) {
+            tokens.extend(err.write_errors());
+            return;
+        }
         let impl_union = impl_union(self).into_token_stream();
         let resolve_owned = define_resolve_owned_for_union(self).into_token_stream();
         let resolve_ref = define_resolve_ref_for_union(self).into_token_stream();
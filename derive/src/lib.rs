@@ -81,6 +81,14 @@ pub fn drive_enum(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     }
 }
 
+#[proc_macro_derive(EnumExtension, attributes(graphql))]
+pub fn drive_enum_extension(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match args::EnumExtension::from_derive_input(&parse_macro_input!(input as DeriveInput)) {
+        Ok(object_args) => object_args.into_token_stream().into(),
+        Err(err) => err.write_errors().into(),
+    }
+}
+
 #[proc_macro_derive(Scalar, attributes(graphql))]
 pub fn drive_scalar(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     match args::Scalar::from_derive_input(&parse_macro_input!(input as DeriveInput)) {
@@ -89,6 +97,22 @@ pub fn drive_scalar(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     }
 }
 
+#[proc_macro_derive(Transparent, attributes(graphql))]
+pub fn drive_transparent(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match args::Transparent::from_derive_input(&parse_macro_input!(input as DeriveInput)) {
+        Ok(transparent_args) => transparent_args.into_token_stream().into(),
+        Err(err) => err.write_errors().into(),
+    }
+}
+
+#[proc_macro_derive(GraphQLError, attributes(graphql))]
+pub fn drive_graphql_error(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match args::GraphQLError::from_derive_input(&parse_macro_input!(input as DeriveInput)) {
+        Ok(error_args) => error_args.into_token_stream().into(),
+        Err(err) => err.write_errors().into(),
+    }
+}
+
 #[proc_macro_derive(App, attributes(graphql))]
 pub fn drive_app(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     match args::App::from_derive_input(&parse_macro_input!(input as DeriveInput)) {
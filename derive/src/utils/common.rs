@@ -1,5 +1,8 @@
+use crate::utils::default_value::DefaultValue;
 use crate::utils::deprecation::Deprecation;
+use crate::utils::errors_as_union::ErrorsAsUnion;
 use crate::utils::impl_block::BaseFnArg;
+use crate::utils::interface_attr::InterfaceDefaultAttr;
 use crate::utils::interface_attr::InterfaceImplAttr;
 use crate::utils::interface_attr::InterfaceMarkAttr;
 use crate::utils::rename_rule::RenameRule;
@@ -24,9 +27,29 @@ pub trait CommonField {
     fn get_deprecation(&self) -> darling::Result<Deprecation> {
         Ok(Deprecation::NoDeprecated)
     }
+    /// the default value of an input field, `#[graphql(default)]`
+    fn get_default_value(&self) -> darling::Result<DefaultValue> {
+        Ok(DefaultValue::NoDefault)
+    }
     fn get_field_rename_rule(&self) -> Option<&RenameRule> {
         None
     }
+    /// resolve list items leniently, `#[graphql(partial_list)]`
+    fn get_partial_list(&self) -> bool {
+        false
+    }
+    /// resolve `Result<T, E>` as a union of `T` and `E`, `#[graphql(errors_as_union)]`
+    fn get_errors_as_union(&self) -> Option<&ErrorsAsUnion> {
+        None
+    }
+    /// add the fields of the type to the parent type, `#[graphql(flatten)]`
+    fn get_flatten(&self) -> bool {
+        false
+    }
+    /// map the value with the function before resolving it, `#[graphql(with = "...")]`
+    fn get_with(&self) -> Option<&syn::Path> {
+        None
+    }
     #[allow(dead_code)]
     fn get_args_rename_rule(&self) -> Option<&RenameRule> {
         None
@@ -40,6 +63,7 @@ pub trait CommonMethod: CommonField {
 pub trait CommonInterfaceAttrs: CommonObject {
     fn get_marks(&self) -> &Vec<InterfaceMarkAttr>;
     fn get_impls(&self) -> &Vec<InterfaceImplAttr>;
+    fn get_interface_defaults(&self) -> &Vec<InterfaceDefaultAttr>;
 }
 
 pub trait CommonArg {
@@ -57,6 +81,13 @@ pub trait CommonArg {
     fn get_doc(&self) -> darling::Result<Option<String>> {
         Ok(None)
     }
+    fn get_deprecation(&self) -> darling::Result<Deprecation> {
+        Ok(Deprecation::NoDeprecated)
+    }
+    /// the default value of the argument, `#[graphql(default)]`
+    fn get_default_value(&self) -> darling::Result<DefaultValue> {
+        Ok(DefaultValue::NoDefault)
+    }
 }
 
 pub trait GetFields<F> {
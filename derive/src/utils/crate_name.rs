@@ -10,7 +10,7 @@ pub fn get_crate_name() -> TokenStream {
         crate_name("dynamic-graphql").expect("dynamic-graphql is present in `Cargo.toml`");
 
     match found_crate {
-        FoundCrate::Itself => quote!(crate),
+        FoundCrate::Itself => quote!(dynamic_graphql),
         FoundCrate::Name(name) => {
             let ident = Ident::new(&name, Span::call_site());
             quote!( #ident )
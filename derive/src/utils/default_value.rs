@@ -0,0 +1,38 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// The default value of an input field or an argument
+#[derive(Debug, Clone, Default)]
+pub enum DefaultValue {
+    #[default]
+    NoDefault,
+    /// `#[graphql(default)]`, the value of `Default::default()`
+    Default,
+    /// `#[graphql(default = expr)]`, the value of the expression converted with `Into`
+    Value(syn::Expr),
+}
+
+impl darling::FromMeta for DefaultValue {
+    fn from_word() -> darling::Result<Self> {
+        Ok(DefaultValue::Default)
+    }
+
+    fn from_expr(expr: &syn::Expr) -> darling::Result<Self> {
+        Ok(DefaultValue::Value(expr.clone()))
+    }
+}
+
+impl DefaultValue {
+    pub fn is_set(&self) -> bool {
+        !matches!(self, DefaultValue::NoDefault)
+    }
+
+    /// the code of the default value of the type `ty`
+    pub fn get_value_code(&self, ty: &syn::Type) -> Option<TokenStream> {
+        match self {
+            DefaultValue::NoDefault => None,
+            DefaultValue::Default => Some(quote!(<#ty as Default>::default())),
+            DefaultValue::Value(expr) => Some(quote!(Into::<#ty>::into(#expr))),
+        }
+    }
+}
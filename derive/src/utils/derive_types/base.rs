@@ -3,6 +3,9 @@ use darling::FromField;
 use darling::FromGenerics;
 use darling::FromVariant;
 use darling::ast::Data;
+use darling::util::Ignored;
+
+use crate::utils::with_context::SetContext;
 
 #[derive(FromDeriveInput)]
 pub struct Base<V: FromVariant, F: FromField, G: FromGenerics> {
@@ -11,14 +14,31 @@ pub struct Base<V: FromVariant, F: FromField, G: FromGenerics> {
     pub data: Data<V, F>,
 }
 
-#[derive(FromField)]
+#[derive(FromField, Debug, Clone)]
 pub struct BaseField {
     pub ident: Option<syn::Ident>,
     pub ty: syn::Type,
 }
 
-#[derive(FromVariant)]
+#[derive(FromVariant, Debug, Clone)]
 pub struct BaseVariant<F: FromField> {
     pub ident: syn::Ident,
     pub fields: darling::ast::Fields<F>,
 }
+
+impl SetContext for BaseField {
+    type Context = Ignored;
+
+    fn set_context(&mut self, _: Self::Context) {}
+}
+
+impl<F> SetContext for BaseVariant<F>
+where
+    F: FromField + SetContext,
+{
+    type Context = F::Context;
+
+    fn set_context(&mut self, context: Self::Context) {
+        self.fields.set_context(context);
+    }
+}
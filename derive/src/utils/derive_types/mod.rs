@@ -9,7 +9,6 @@ pub use newtype_struct::NewtypeStruct;
 pub use newtype_variant::NewtypeVariant;
 pub use tuple_field::TupleField;
 pub use unit_struct::UnitStruct;
-pub use unit_variant::UnitVariant;
 
 mod base;
 
@@ -17,7 +16,6 @@ mod base_enum;
 mod named_enum;
 mod newtype_enum;
 mod tuple_enum;
-mod unit_enum;
 
 mod base_struct;
 mod named_struct;
@@ -30,4 +28,3 @@ mod tuple_field;
 
 mod named_variant;
 mod newtype_variant;
-mod unit_variant;
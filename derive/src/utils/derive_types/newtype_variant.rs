@@ -5,6 +5,7 @@ use super::BaseVariant;
 use super::TupleField;
 use crate::utils::with_context::SetContext;
 
+#[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct NewtypeVariant<F: FromField = TupleField> {
     pub ident: syn::Ident,
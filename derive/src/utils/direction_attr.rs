@@ -0,0 +1,18 @@
+use darling::FromMeta;
+
+/// overrides of the field attributes for one direction of a type that is both an object and an
+/// input object, `#[graphql(input(skip))]` or `#[graphql(output(name = "..."))]`
+#[derive(FromMeta, Default, Debug, Clone)]
+pub struct DirectionAttr {
+    #[darling(default)]
+    pub skip: bool,
+
+    #[darling(default)]
+    pub name: Option<String>,
+}
+
+impl DirectionAttr {
+    pub fn is_set(&self) -> bool {
+        self.skip || self.name.is_some()
+    }
+}
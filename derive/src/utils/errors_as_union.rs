@@ -0,0 +1,17 @@
+/// `#[graphql(errors_as_union)]` or `#[graphql(errors_as_union = "UnionName")]`
+#[derive(Debug, Clone, Default)]
+pub struct ErrorsAsUnion {
+    pub name: Option<String>,
+}
+
+impl darling::FromMeta for ErrorsAsUnion {
+    fn from_word() -> darling::Result<Self> {
+        Ok(ErrorsAsUnion { name: None })
+    }
+
+    fn from_string(value: &str) -> darling::Result<Self> {
+        Ok(ErrorsAsUnion {
+            name: Some(value.to_string()),
+        })
+    }
+}
@@ -12,7 +12,8 @@ pub struct BaseItemTrait<Method = BaseMethod, Generics = ()> {
     pub ident: syn::Ident,
     pub generics: Generics,
     pub methods: Methods<Method>,
-    // todo consts, types, super_traits
+    pub super_traits: Vec<syn::Path>,
+    // todo consts, types
 }
 
 impl<Method, Generics> FromItemTrait for BaseItemTrait<Method, Generics>
@@ -25,10 +26,26 @@ where
             ident: item_trait.ident.clone(),
             generics: FromGenerics::from_generics(&item_trait.generics)?,
             methods: Methods::from_trait_item_methods(&mut item_trait.items.iter_mut())?,
+            super_traits: get_super_traits(item_trait),
         })
     }
 }
 
+fn get_super_traits(item_trait: &syn::ItemTrait) -> Vec<syn::Path> {
+    item_trait
+        .supertraits
+        .iter()
+        .filter_map(|bound| match bound {
+            syn::TypeParamBound::Trait(bound)
+                if matches!(bound.modifier, syn::TraitBoundModifier::None) =>
+            {
+                Some(bound.path.clone())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
 impl<Method, Generics> SetContext for BaseItemTrait<Method, Generics>
 where
     Method: FromTraitItemFn + SetContext,
@@ -20,6 +20,12 @@ pub struct InterfaceImplAttr {
     pub span: Span,
 }
 
+/// `#[graphql(interface_default(field))]` or `#[graphql(interface_default(Trait::field))]`
+#[derive(Debug, Clone)]
+pub struct InterfaceDefaultAttr {
+    pub paths: Vec<syn::Path>,
+}
+
 struct MatchMarkWith(MatchMetaPath);
 
 impl MatchNestedMetaList for MatchMarkWith {
@@ -75,3 +81,19 @@ impl FromMeta for InterfaceImplAttr {
         Err(darling::Error::custom("Invalid interface attribute"))
     }
 }
+
+impl FromMeta for InterfaceDefaultAttr {
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        if items.is_empty() {
+            return Err(darling::Error::custom("Expected at least one field"));
+        }
+        let paths = items
+            .iter()
+            .map(|item| match item {
+                NestedMeta::Meta(syn::Meta::Path(path)) => Ok(path.clone()),
+                _ => Err(darling::Error::custom("Expected a field name").with_span(item)),
+            })
+            .collect::<darling::Result<Vec<_>>>()?;
+        Ok(InterfaceDefaultAttr { paths })
+    }
+}
@@ -1,14 +1,18 @@
 pub mod attributes;
 pub mod common;
 pub mod crate_name;
+pub mod default_value;
 pub mod deprecation;
 pub mod derive_types;
+pub mod direction_attr;
 pub mod docs_utils;
 pub mod error;
+pub mod errors_as_union;
 pub mod impl_block;
 pub mod interface_attr;
 pub mod macros;
 pub mod meta_match;
+pub mod paired_attrs;
 pub mod path_attr;
 pub mod register_attr;
 pub mod rename_rule;
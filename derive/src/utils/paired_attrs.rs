@@ -0,0 +1,178 @@
+//! The attributes of the derives which can be used together on a type with `input_name`, the
+//! `SimpleObject` and `InputObject` derives of a struct, and the `Union` and `OneOfInput` derives
+//! of an enum.
+//!
+//! Both derives of a pair parse the same attributes, each one uses its own and rejects the others
+//! without `input_name`.
+
+use darling::FromAttributes;
+use darling::FromMeta;
+
+use crate::utils::default_value::DefaultValue;
+use crate::utils::deprecation::Deprecation;
+use crate::utils::direction_attr::DirectionAttr;
+use crate::utils::interface_attr::InterfaceDefaultAttr;
+use crate::utils::interface_attr::InterfaceImplAttr;
+use crate::utils::interface_attr::InterfaceMarkAttr;
+use crate::utils::path_attr::PathAttr;
+use crate::utils::register_attr::RegisterAttr;
+use crate::utils::rename_rule::RenameRule;
+
+/// the attributes of the fields of the objects and of the input objects, also of the fields of
+/// the struct variants of the unions and of the oneOf input objects
+#[derive(FromAttributes, Debug, Clone)]
+#[darling(attributes(graphql))]
+pub struct ObjectFieldAttrs {
+    #[darling(default)]
+    pub skip: bool,
+
+    #[darling(default)]
+    pub name: Option<String>,
+
+    #[darling(default)]
+    pub deprecation: Deprecation,
+
+    #[darling(default)]
+    pub flatten: bool,
+
+    /// `InputObject` only, the default value of the input field
+    #[darling(default)]
+    pub default: DefaultValue,
+
+    /// `SimpleObject` only
+    #[darling(default)]
+    pub partial_list: bool,
+
+    /// `SimpleObject` only, the function which maps the value of the field before resolving it
+    #[darling(default)]
+    pub with: Option<syn::Path>,
+
+    /// overrides of the input derive of a type with `input_name`
+    #[darling(default)]
+    pub input: DirectionAttr,
+
+    /// overrides of the output derive of a type with `input_name`
+    #[darling(default)]
+    pub output: DirectionAttr,
+}
+
+/// an extra field resolved by a function of the object, `computed(name = "...", ty = "...", with = "...")`,
+/// the name is used verbatim like the `name` of the fields, `rename_fields` doesn't apply to it
+#[derive(FromMeta, Debug, Clone)]
+pub struct ComputedAttr {
+    pub name: String,
+    pub ty: syn::Type,
+    pub with: syn::Path,
+}
+
+/// the attributes of the objects and of the input objects
+#[derive(FromAttributes, Debug, Clone)]
+#[darling(attributes(graphql))]
+pub struct ObjectAttrs {
+    #[darling(default)]
+    pub name: Option<String>,
+
+    #[darling(default)]
+    #[darling(rename = "get_type_name")]
+    pub type_name: bool,
+
+    #[darling(default)]
+    pub rename_fields: Option<RenameRule>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "register")]
+    pub registers: Vec<RegisterAttr>,
+
+    /// the name of the input object of a type that derives both `SimpleObject` and `InputObject`
+    #[darling(default)]
+    pub input_name: Option<String>,
+
+    /// `SimpleObject` only
+    #[darling(default)]
+    pub root: bool,
+
+    #[darling(skip)]
+    pub mutation_root: bool,
+
+    /// `SimpleObject` only
+    #[darling(default, multiple)]
+    #[darling(rename = "mark")]
+    pub marks: Vec<InterfaceMarkAttr>,
+
+    /// `SimpleObject` only
+    #[darling(default, multiple)]
+    #[darling(rename = "implements")]
+    pub impls: Vec<InterfaceImplAttr>,
+
+    /// `SimpleObject` only
+    #[darling(default, multiple)]
+    #[darling(rename = "interface_default")]
+    pub interface_defaults: Vec<InterfaceDefaultAttr>,
+
+    /// `SimpleObject` only
+    #[darling(default, multiple)]
+    #[darling(rename = "extends_union")]
+    pub extends_unions: Vec<PathAttr>,
+
+    /// `SimpleObject` only
+    #[darling(default, multiple)]
+    pub computed: Vec<ComputedAttr>,
+
+    /// `SimpleObject` only, the object is registered by its `ResolvedObjectFields`, which adds
+    /// the resolvers fields
+    #[darling(default)]
+    pub complex: bool,
+
+    /// `InputObject` only
+    #[darling(default)]
+    pub collect_errors: bool,
+
+    /// `InputObject` only, the type updated by the `apply_to` method of the patch input object
+    #[darling(default)]
+    pub patch_for: Option<syn::Path>,
+}
+
+/// the attributes of the variants of the unions and of the oneOf input objects
+#[derive(FromAttributes, Debug, Clone)]
+#[darling(attributes(graphql))]
+pub struct UnionVariantAttrs {
+    /// the name of the object of struct and unit variants, or of the input object of struct
+    /// variants
+    #[darling(default)]
+    pub type_name: Option<String>,
+
+    /// `OneOfInput` only
+    #[darling(default)]
+    pub skip: bool,
+
+    /// `OneOfInput` only
+    #[darling(default)]
+    pub name: Option<String>,
+
+    /// `OneOfInput` only
+    #[darling(default)]
+    pub deprecation: Deprecation,
+}
+
+/// the attributes of the unions and of the oneOf input objects
+#[derive(FromAttributes, Debug, Clone)]
+#[darling(attributes(graphql))]
+pub struct UnionAttrs {
+    #[darling(default)]
+    pub name: Option<String>,
+
+    #[darling(default)]
+    #[darling(rename = "get_type_name")]
+    pub type_name: bool,
+
+    #[darling(default)]
+    pub rename_fields: Option<RenameRule>,
+
+    /// the name of the oneOf input object of an enum that derives both `Union` and `OneOfInput`
+    #[darling(default)]
+    pub input_name: Option<String>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "register")]
+    pub registers: Vec<RegisterAttr>,
+}
@@ -1,6 +1,8 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
+use crate::utils::crate_name::get_crate_name;
+
 #[allow(dead_code)]
 pub fn is_type_ref(ty: &syn::Type) -> bool {
     matches!(ty, syn::Type::Reference(_))
@@ -82,3 +84,126 @@ pub fn get_value_type(ty: &syn::Type) -> Option<TokenStream> {
     }
     None
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImplListKind {
+    Iterator,
+    Stream,
+}
+
+/// check if the type is an `impl Iterator<Item = T>` or `impl Stream<Item = T>`, and get `T`
+pub fn get_impl_list_item(ty: &syn::Type) -> Option<(ImplListKind, &syn::Type)> {
+    let syn::Type::ImplTrait(impl_trait) = ty else {
+        return None;
+    };
+    impl_trait.bounds.iter().find_map(|bound| {
+        let syn::TypeParamBound::Trait(bound) = bound else {
+            return None;
+        };
+        let segment = bound.path.segments.last()?;
+        let kind = if segment.ident == "Iterator" {
+            ImplListKind::Iterator
+        } else if segment.ident == "Stream" {
+            ImplListKind::Stream
+        } else {
+            return None;
+        };
+        let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+            return None;
+        };
+        args.args.iter().find_map(|arg| match arg {
+            syn::GenericArgument::AssocType(assoc) if assoc.ident == "Item" => {
+                Some((kind, &assoc.ty))
+            }
+            _ => None,
+        })
+    })
+}
+
+/// get the ok and error types of `Result<T, E>`
+pub fn get_result_types(ty: &syn::Type) -> Option<(&syn::Type, &syn::Type)> {
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Result" {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    let mut types = args.args.iter().filter_map(|arg| match arg {
+        syn::GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    });
+    Some((types.next()?, types.next()?))
+}
+
+/// get the inner type of `Option<T>` or the ok type of `Result<T, E>`
+fn get_wrapped_type<'a>(ty: &'a syn::Type, wrapper: &str) -> Option<&'a syn::Type> {
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != wrapper {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        syn::GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    }
+}
+
+/// replace `impl Iterator<Item = T>` and `impl Stream<Item = T>` with `Vec<T>`
+pub fn replace_impl_list_with_vec(ty: &syn::Type) -> syn::Type {
+    if let Some((_, item)) = get_impl_list_item(ty) {
+        return syn::parse_quote!(Vec<#item>);
+    }
+    let mut ty = ty.clone();
+    if let syn::Type::Path(type_path) = &mut ty {
+        for segment in &mut type_path.path.segments {
+            if let syn::PathArguments::AngleBracketed(args) = &mut segment.arguments {
+                for arg in &mut args.args {
+                    if let syn::GenericArgument::Type(ty) = arg {
+                        *ty = replace_impl_list_with_vec(ty);
+                    }
+                }
+            }
+        }
+    }
+    ty
+}
+
+/// Code to convert `value` of type `ty` into a resolvable list, if `ty` is
+/// `impl Iterator`, `impl Stream` or one of them wrapped in `Option` or `Result`.
+pub fn get_impl_list_conversion(ty: &syn::Type) -> Option<TokenStream> {
+    let crate_name = get_crate_name();
+    if let Some((kind, _)) = get_impl_list_item(ty) {
+        return Some(match kind {
+            ImplListKind::Iterator => quote!(#crate_name::internal::ResolveIter(value)),
+            ImplListKind::Stream => quote!(#crate_name::internal::collect_stream(value).await),
+        });
+    }
+    if let Some(inner) = get_wrapped_type(ty, "Option") {
+        let conversion = get_impl_list_conversion(inner)?;
+        return Some(quote! {
+            match value {
+                Some(value) => Some(#conversion),
+                None => None,
+            }
+        });
+    }
+    if let Some(inner) = get_wrapped_type(ty, "Result") {
+        let conversion = get_impl_list_conversion(inner)?;
+        return Some(quote! {
+            match value {
+                Ok(value) => Ok(#conversion),
+                Err(err) => Err(err),
+            }
+        });
+    }
+    None
+}
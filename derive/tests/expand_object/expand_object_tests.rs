@@ -467,6 +467,101 @@ async fn test_ref_query() {
     );
 }
 
+#[tokio::test]
+async fn test_iter_query() {
+    #[derive(SimpleObject)]
+    struct Example {
+        field: String,
+    }
+
+    #[derive(ExpandObject)]
+    struct ExampleQuery<'a>(&'a Query);
+
+    #[ExpandObjectFields]
+    impl<'a> ExampleQuery<'a> {
+        fn examples(&self) -> impl Iterator<Item = &'a Example> {
+            self.0.examples.iter()
+        }
+        async fn fields(&self) -> impl futures_util::Stream<Item = String> + Send {
+            futures_util::stream::iter(self.0.examples.iter().map(|e| e.field.clone()))
+        }
+    }
+
+    #[derive(App)]
+    struct ExampleApp(Example, ExampleQuery<'static>);
+
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    struct Query {
+        foo: String,
+        #[graphql(skip)]
+        examples: Vec<Example>,
+    }
+
+    #[derive(App)]
+    struct App(Query, ExampleApp);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Example {
+      field: String!
+    }
+
+    type Query {
+      foo: String!
+      examples: [Example!]!
+      fields: [String!]!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            examples {
+                field
+            }
+            fields
+        }
+    "#;
+
+    let root = Query {
+        foo: "foo".to_string(),
+        examples: vec![
+            Example {
+                field: "a".to_string(),
+            },
+            Example {
+                field: "b".to_string(),
+            },
+        ],
+    };
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(root));
+
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!(
+            {
+                "examples": [{ "field": "a" }, { "field": "b" }],
+                "fields": ["a", "b"]
+            }
+        )
+    );
+}
+
 #[tokio::test]
 async fn test_auto_register() {
     #[derive(SimpleObject)]
@@ -1,4 +1,6 @@
 mod schema_utils;
 mod gql_enum {
+    mod extension_tests;
+    mod other_tests;
     mod tests;
 }
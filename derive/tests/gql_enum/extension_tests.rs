@@ -0,0 +1,230 @@
+use std::borrow::Cow;
+
+use dynamic_graphql::App;
+use dynamic_graphql::DynEnum;
+use dynamic_graphql::DynEnumTag;
+use dynamic_graphql::EnumExtension;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::dynamic;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+struct Permission;
+
+impl DynEnumTag for Permission {
+    fn get_enum_name() -> Cow<'static, str> {
+        "Permission".into()
+    }
+    fn get_enum_description() -> Option<Cow<'static, str>> {
+        Some("The permissions of all the modules".into())
+    }
+}
+
+mod users {
+    use super::*;
+
+    #[derive(EnumExtension, Debug, PartialEq)]
+    #[graphql(extends(Permission))]
+    pub enum UserPermission {
+        /// Read the users
+        ReadUser,
+        #[graphql(deprecation = "use READ_USER")]
+        ListUsers,
+    }
+}
+
+mod posts {
+    use super::*;
+
+    #[derive(EnumExtension, Debug, PartialEq)]
+    #[graphql(extends(Permission), rename_items = "camelCase")]
+    pub enum PostPermission {
+        WritePost,
+    }
+}
+
+#[test]
+fn test_conversions() {
+    use self::posts::PostPermission;
+    use self::users::UserPermission;
+
+    let value: DynEnum<Permission> = UserPermission::ReadUser.into();
+    assert_eq!(value, "READ_USER");
+    assert_eq!(
+        UserPermission::try_from(value.clone()),
+        Ok(UserPermission::ReadUser)
+    );
+    assert_eq!(PostPermission::try_from(value.clone()), Err(value));
+
+    let value: DynEnum<Permission> = PostPermission::WritePost.into();
+    assert_eq!(value.as_str(), "writePost");
+}
+
+#[tokio::test]
+async fn test_schema() {
+    use self::posts::PostPermission;
+    use self::users::UserPermission;
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn permissions(&self) -> Vec<DynEnum<Permission>> {
+            vec![
+                UserPermission::ReadUser.into(),
+                PostPermission::WritePost.into(),
+            ]
+        }
+        fn post_permission(&self) -> PostPermission {
+            PostPermission::WritePost
+        }
+        fn has(&self, permission: DynEnum<Permission>) -> String {
+            permission.to_string()
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query, UserPermission, PostPermission);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    "The permissions of all the modules"
+    enum Permission {
+      writePost
+      "Read the users" READ_USER
+      LIST_USERS @deprecated(reason: "use READ_USER")
+    }
+
+    type Query {
+      permissions: [Permission!]!
+      postPermission: Permission!
+      has(permission: Permission!): String!
+    }
+
+    "Marks an element of a GraphQL schema as no longer supported."
+    directive @deprecated(reason: String = "No longer supported") on FIELD_DEFINITION | ARGUMENT_DEFINITION | INPUT_FIELD_DEFINITION | ENUM_VALUE
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            permissions
+            postPermission
+            has(permission: LIST_USERS)
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "permissions": ["READ_USER", "writePost"],
+            "postPermission": "writePost",
+            "has": "LIST_USERS",
+        })
+    );
+
+    let query = r#"
+        query {
+            has(permission: DeleteUser)
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(
+        res.errors[0].message,
+        r#"Invalid value for argument "permission", enumeration type "Permission" does not contain the value "DeleteUser""#
+    );
+
+    let query = r#"
+        query($permission: Permission!) {
+            has(permission: $permission)
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query)
+        .variables(dynamic_graphql::Variables::from_json(
+            serde_json::json!({ "permission": "DeleteUser" }),
+        ))
+        .root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(
+        res.errors[0].message,
+        r#"Invalid value for argument "permission", enumeration type "Permission" does not contain the value "DeleteUser""#
+    );
+}
+
+#[test]
+fn test_update_enum() {
+    use self::users::UserPermission;
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn permission(&self) -> DynEnum<Permission> {
+            DynEnum::new("Admin")
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query, UserPermission);
+
+    let registry = dynamic_graphql::internal::Registry::new()
+        .register::<App>()
+        .update_enum("Permission", "Admin", |object| {
+            object.item(dynamic::EnumItem::new("Admin").description("All the permissions"))
+        });
+    let schema = registry.create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    "The permissions of all the modules"
+    enum Permission {
+      "Read the users" READ_USER
+      LIST_USERS @deprecated(reason: "use READ_USER")
+      "All the permissions" Admin
+    }
+
+    type Query {
+      permission: Permission!
+    }
+
+    "Marks an element of a GraphQL schema as no longer supported."
+    directive @deprecated(reason: String = "No longer supported") on FIELD_DEFINITION | ARGUMENT_DEFINITION | INPUT_FIELD_DEFINITION | ENUM_VALUE
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+}
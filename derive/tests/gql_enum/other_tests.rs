@@ -0,0 +1,138 @@
+use dynamic_graphql::App;
+use dynamic_graphql::Enum;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::dynamic;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use dynamic_graphql::internal::Registry;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(Enum, Debug, PartialEq)]
+enum Status {
+    /// Waiting for review
+    Pending,
+    #[graphql(deprecation = "use PENDING")]
+    Draft,
+    #[graphql(other)]
+    Other(String),
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn statuses(&self) -> Vec<Status> {
+        vec![Status::Pending, Status::Other("ARCHIVED".to_string())]
+    }
+    fn unknown_status(&self) -> Status {
+        Status::Other("UNKNOWN".to_string())
+    }
+    fn status(&self, status: Status) -> String {
+        format!("{:?}", status)
+    }
+}
+
+#[derive(App)]
+struct App(Query);
+
+fn create_schema() -> dynamic::Schema {
+    Registry::new()
+        .register::<App>()
+        .update_enum("Status", "Archived", |object| {
+            object.item(dynamic::EnumItem::new("ARCHIVED"))
+        })
+        .create_schema()
+        .finish()
+        .unwrap()
+}
+
+#[test]
+fn test_schema() {
+    let schema = create_schema();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Query {
+      statuses: [Status!]!
+      unknownStatus: Status!
+      status(status: Status!): String!
+    }
+
+    enum Status {
+      "Waiting for review" PENDING
+      DRAFT @deprecated(reason: "use PENDING")
+      ARCHIVED
+    }
+
+    "Marks an element of a GraphQL schema as no longer supported."
+    directive @deprecated(reason: String = "No longer supported") on FIELD_DEFINITION | ARGUMENT_DEFINITION | INPUT_FIELD_DEFINITION | ENUM_VALUE
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn test_other_variant() {
+    let schema = create_schema();
+
+    let query = r#"
+        query {
+            statuses
+            pending: status(status: PENDING)
+            archived: status(status: ARCHIVED)
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "statuses": ["PENDING", "ARCHIVED"],
+            "pending": "Pending",
+            "archived": "Other(\"ARCHIVED\")",
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_unregistered_other_value() {
+    let schema = create_schema();
+
+    let query = r#"
+        query {
+            status(status: UNKNOWN)
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+
+    let query = r#"
+        query {
+            unknownStatus
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(
+        res.errors[0].message,
+        "internal: invalid item for enum \"Status\""
+    );
+}
@@ -46,6 +46,37 @@ fn test_remote() {
     assert!(matches!(example, Example::Bar));
 }
 
+#[test]
+fn test_remote_with_renamed_variants() {
+    enum Org {
+        Foo,
+        Baz,
+        Unknown(String),
+    }
+
+    #[derive(Enum, Debug, PartialEq)]
+    #[graphql(remote(Org))]
+    enum Example {
+        Foo,
+        #[graphql(remote(Baz))]
+        Bar,
+        #[graphql(other, remote(Unknown))]
+        Other(String),
+    }
+
+    let org: Org = Example::Bar.into();
+    assert!(matches!(org, Org::Baz));
+
+    let org: Org = Example::Other("QUX".to_string()).into();
+    assert!(matches!(org, Org::Unknown(value) if value == "QUX"));
+
+    let example: Example = Org::Baz.into();
+    assert_eq!(example, Example::Bar);
+
+    let example: Example = Org::Foo.into();
+    assert_eq!(example, Example::Foo);
+}
+
 #[tokio::test]
 async fn test_schema() {
     #[derive(Enum)]
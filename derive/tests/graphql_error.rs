@@ -0,0 +1,4 @@
+mod schema_utils;
+mod graphql_error {
+    mod tests;
+}
@@ -0,0 +1,248 @@
+use dynamic_graphql::App;
+use dynamic_graphql::Context;
+use dynamic_graphql::ErrorCode;
+use dynamic_graphql::ErrorCodes;
+use dynamic_graphql::ErrorExtensions;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::GraphQLError;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use dynamic_graphql::experimental::GetSchemaData;
+use dynamic_graphql::internal::GraphQLError as _;
+use dynamic_graphql::internal::Registry;
+
+#[derive(GraphQLError, Debug)]
+enum UserError {
+    /// The user does not exist
+    #[graphql(message = "User {id} not found")]
+    NotFound { id: String },
+    #[graphql(code = "FORBIDDEN", message = "Permission denied")]
+    PermissionDenied {
+        #[graphql(name = "requiredRole")]
+        role: String,
+        #[graphql(skip)]
+        #[allow(dead_code)]
+        reason: String,
+        retry_after: i32,
+    },
+    #[graphql(message = "Internal error: {0}")]
+    Internal(String),
+    #[graphql(message = "Rate limited")]
+    RateLimited,
+}
+
+#[test]
+fn test_error_codes() {
+    assert_eq!(
+        UserError::ERROR_CODES,
+        &[
+            ErrorCode {
+                error: "UserError",
+                code: "NOT_FOUND",
+                description: Some("The user does not exist"),
+            },
+            ErrorCode {
+                error: "UserError",
+                code: "FORBIDDEN",
+                description: None,
+            },
+            ErrorCode {
+                error: "UserError",
+                code: "INTERNAL",
+                description: None,
+            },
+            ErrorCode {
+                error: "UserError",
+                code: "RATE_LIMITED",
+                description: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_extend() {
+    let err = UserError::NotFound {
+        id: "1".to_string(),
+    }
+    .extend();
+    assert_eq!(err.message, "User 1 not found");
+    assert_eq!(
+        serde_json::to_value(&err.extensions).unwrap(),
+        serde_json::json!({ "code": "NOT_FOUND", "id": "1" })
+    );
+
+    let err = UserError::PermissionDenied {
+        role: "admin".to_string(),
+        reason: "secret".to_string(),
+        retry_after: 10,
+    }
+    .extend();
+    assert_eq!(err.message, "Permission denied");
+    assert_eq!(
+        serde_json::to_value(&err.extensions).unwrap(),
+        serde_json::json!({ "code": "FORBIDDEN", "requiredRole": "admin", "retryAfter": 10 })
+    );
+
+    let err = UserError::Internal("oops".to_string()).extend();
+    assert_eq!(err.message, "Internal error: oops");
+    assert_eq!(
+        serde_json::to_value(&err.extensions).unwrap(),
+        serde_json::json!({ "code": "INTERNAL" })
+    );
+}
+
+#[derive(GraphQLError, thiserror::Error, Debug)]
+enum PaymentError {
+    #[error("card {0} declined")]
+    Declined(String),
+    #[graphql(message = "Insufficient funds")]
+    #[error("insufficient funds: {missing}")]
+    InsufficientFunds { missing: i32 },
+}
+
+#[test]
+fn test_display_message() {
+    let err = PaymentError::Declined("visa".to_string()).extend();
+    assert_eq!(err.message, "card visa declined");
+    assert_eq!(
+        serde_json::to_value(&err.extensions).unwrap(),
+        serde_json::json!({ "code": "DECLINED" })
+    );
+
+    let err = PaymentError::InsufficientFunds { missing: 5 }.extend();
+    assert_eq!(err.message, "Insufficient funds");
+    assert_eq!(
+        serde_json::to_value(&err.extensions).unwrap(),
+        serde_json::json!({ "code": "INSUFFICIENT_FUNDS", "missing": 5 })
+    );
+}
+
+#[tokio::test]
+async fn test_display_query() {
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn pay(&self) -> Result<bool, PaymentError> {
+            Err(PaymentError::Declined("visa".to_string()))
+        }
+        fn refund(&self) -> dynamic_graphql::Result<bool> {
+            // the blanket conversion of `Display` errors has no extensions
+            Err(PaymentError::Declined("visa".to_string()))?
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query, PaymentError);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            pay
+            refund
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 2);
+    assert_eq!(res.errors[0].message, "card visa declined");
+    assert_eq!(
+        serde_json::to_value(&res.errors[0].extensions).unwrap(),
+        serde_json::json!({ "code": "DECLINED" })
+    );
+    assert_eq!(res.errors[1].message, "card visa declined");
+    assert_eq!(res.errors[1].extensions, None);
+}
+
+#[tokio::test]
+async fn test_query() {
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn user(&self, id: String) -> Result<Option<String>, UserError> {
+            Err(UserError::NotFound { id })
+        }
+        fn users(&self) -> dynamic_graphql::Result<Option<Vec<String>>> {
+            Err(UserError::RateLimited.extend())
+        }
+        fn error_codes(&self, ctx: &Context) -> Vec<String> {
+            ctx.get_schema_data()
+                .get::<ErrorCodes>()
+                .map(|codes| codes.codes().iter().map(|c| c.code.to_string()).collect())
+                .unwrap_or_default()
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query, UserError);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            user(id: "1")
+            users
+            errorCodes
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(
+        res.data.into_json().unwrap(),
+        serde_json::json!({
+            "errorCodes": ["NOT_FOUND", "FORBIDDEN", "INTERNAL", "RATE_LIMITED"],
+        })
+    );
+    assert_eq!(res.errors.len(), 2);
+    assert_eq!(res.errors[0].message, "User 1 not found");
+    assert_eq!(
+        serde_json::to_value(&res.errors[0].extensions).unwrap(),
+        serde_json::json!({ "code": "NOT_FOUND", "id": "1" })
+    );
+    assert_eq!(res.errors[1].message, "Rate limited");
+    assert_eq!(
+        serde_json::to_value(&res.errors[1].extensions).unwrap(),
+        serde_json::json!({ "code": "RATE_LIMITED" })
+    );
+}
+
+#[test]
+fn test_registry() {
+    #[derive(GraphQLError, Debug)]
+    #[graphql(name = "OrderErrors")]
+    #[allow(dead_code)]
+    enum OrderError {
+        #[graphql(message = "Not found")]
+        NotFound,
+    }
+
+    let registry = Registry::new()
+        .register::<UserError>()
+        .register::<OrderError>()
+        .register::<UserError>();
+    let codes = registry
+        .error_codes()
+        .iter()
+        .map(|code| format!("{}.{}", code.error, code.code))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        codes,
+        vec![
+            "UserError.NOT_FOUND",
+            "UserError.FORBIDDEN",
+            "UserError.INTERNAL",
+            "UserError.RATE_LIMITED",
+            "OrderErrors.NOT_FOUND",
+        ]
+    );
+}
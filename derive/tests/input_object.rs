@@ -1,6 +1,13 @@
 mod schema_utils;
 mod input_object {
+    mod collect_errors_tests;
+    mod default_value_tests;
+    mod deprecation_tests;
+    mod flatten_tests;
+    mod generic_tests;
     mod list_tests;
+    mod patch_tests;
     mod tests;
+    mod to_input_value_tests;
     mod type_tests;
 }
@@ -0,0 +1,169 @@
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::InputObject;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::Scalar;
+use dynamic_graphql::ScalarValue;
+use dynamic_graphql::Value;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+#[derive(Scalar)]
+struct Zip(String);
+
+impl ScalarValue for Zip {
+    fn from_value(value: Value) -> dynamic_graphql::Result<Self> {
+        match value {
+            Value::String(value) if value.len() == 5 => Ok(Zip(value)),
+            _ => Err(dynamic_graphql::Error::new("invalid zip")),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.0.clone())
+    }
+}
+
+#[derive(InputObject)]
+#[graphql(collect_errors)]
+struct AddressInput {
+    zip: Zip,
+    city: String,
+}
+
+#[derive(InputObject)]
+#[graphql(collect_errors)]
+struct UserInput {
+    name: String,
+    zip: Zip,
+    addresses: Vec<AddressInput>,
+}
+
+#[derive(InputObject)]
+struct ZipsInput {
+    zips: Vec<Zip>,
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn create_user(&self, input: UserInput) -> String {
+        let cities: Vec<_> = input
+            .addresses
+            .iter()
+            .map(|address| format!("{} {}", address.zip.0, address.city))
+            .collect();
+        format!("{} {}: {}", input.name, input.zip.0, cities.join(", "))
+    }
+    fn zips(&self, input: ZipsInput) -> Vec<String> {
+        input.zips.into_iter().map(|zip| zip.0).collect()
+    }
+}
+
+#[derive(App)]
+struct App(Query);
+
+#[tokio::test]
+async fn test_valid_input() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            createUser(input: { name: "foo", zip: "12345", addresses: [{ zip: "54321", city: "bar" }] })
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({ "createUser": "foo 12345: 54321 bar" })
+    );
+}
+
+#[tokio::test]
+async fn test_collect_errors() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            createUser(input: {
+                name: "foo",
+                zip: "1",
+                addresses: [
+                    { zip: "12345", city: "a" },
+                    { zip: "2", city: "b" },
+                    { zip: "3", city: "c" },
+                ]
+            })
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(
+        res.errors[0].message,
+        r#"Invalid value for argument "input": Failed to parse "UserInput": Invalid value for field "zip": Failed to parse "Zip": invalid zip (and 2 more errors)"#
+    );
+    assert_eq!(
+        serde_json::to_value(&res.errors[0].extensions).unwrap(),
+        serde_json::json!({
+            "errors": [
+                {
+                    "path": "input.zip",
+                    "message": r#"Failed to parse "Zip": invalid zip"#,
+                },
+                {
+                    "path": "input.addresses[1].zip",
+                    "message": r#"Failed to parse "Zip": invalid zip"#,
+                },
+                {
+                    "path": "input.addresses[2].zip",
+                    "message": r#"Failed to parse "Zip": invalid zip"#,
+                },
+            ],
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_list_errors() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            zips(input: { zips: ["12345", "1", "2"] })
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(
+        res.errors[0].message,
+        r#"Invalid value for argument "input": Failed to parse "ZipsInput": Invalid value for field "zips": Failed to parse "Zip": invalid zip (occurred while parsing "[Zip!]") (and 1 more error)"#
+    );
+    assert_eq!(
+        serde_json::to_value(&res.errors[0].extensions).unwrap(),
+        serde_json::json!({
+            "errors": [
+                {
+                    "path": "input.zips[1]",
+                    "message": r#"Failed to parse "Zip": invalid zip (occurred while parsing "[Zip!]")"#,
+                },
+                {
+                    "path": "input.zips[2]",
+                    "message": r#"Failed to parse "Zip": invalid zip (occurred while parsing "[Zip!]")"#,
+                },
+            ],
+        })
+    );
+}
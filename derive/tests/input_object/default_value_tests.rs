@@ -0,0 +1,122 @@
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::InputObject;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(InputObject)]
+struct SearchInput {
+    query: String,
+    #[graphql(default = 10)]
+    limit: i32,
+    #[graphql(default)]
+    offset: i32,
+    #[graphql(default = "en")]
+    language: String,
+    #[graphql(default = Some(true))]
+    exact: Option<bool>,
+}
+
+#[derive(InputObject)]
+#[graphql(collect_errors)]
+struct PageInput {
+    #[graphql(default = 20)]
+    size: i32,
+    number: i32,
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn search(&self, input: SearchInput) -> String {
+        format!(
+            "{} {} {} {} {:?}",
+            input.query, input.limit, input.offset, input.language, input.exact
+        )
+    }
+    fn page(&self, input: PageInput) -> String {
+        format!("{} {}", input.size, input.number)
+    }
+    fn items(
+        &self,
+        #[graphql(default = 5)] first: i32,
+        #[graphql(default)] filter: String,
+    ) -> String {
+        format!("{} {:?}", first, filter)
+    }
+}
+
+#[derive(App)]
+struct App(Query);
+
+#[tokio::test]
+async fn test_schema() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    input PageInput {
+      size: Int! = 20
+      number: Int!
+    }
+
+    type Query {
+      search(input: SearchInput!): String!
+      page(input: PageInput!): String!
+      items(first: Int! = 5, filter: String! = ""): String!
+    }
+
+    input SearchInput {
+      query: String!
+      limit: Int! = 10
+      offset: Int! = 0
+      language: String! = "en"
+      exact: Boolean = true
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn test_query() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            defaults: search(input: { query: "foo" })
+            values: search(input: { query: "foo", limit: 1, offset: 2, language: "fr", exact: null })
+            page(input: { number: 3 })
+            defaultItems: items
+            items(first: 1, filter: "bar")
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors, vec![]);
+    assert_eq!(
+        res.data.into_json().unwrap(),
+        serde_json::json!({
+            "defaults": "foo 10 0 en Some(true)",
+            "values": "foo 1 2 fr None",
+            "page": "20 3",
+            "defaultItems": "5 \"\"",
+            "items": "1 \"bar\"",
+        })
+    );
+}
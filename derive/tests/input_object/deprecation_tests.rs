@@ -0,0 +1,175 @@
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::InputObject;
+use dynamic_graphql::OneOfInput;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(InputObject)]
+struct UserInput {
+    name: String,
+    #[graphql(deprecation = "use name")]
+    username: Option<String>,
+    #[graphql(deprecation)]
+    nickname: Option<String>,
+}
+
+#[derive(OneOfInput)]
+enum UserBy {
+    Id(String),
+    #[graphql(deprecation = "use id")]
+    Email(String),
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn create_user(&self, input: UserInput) -> String {
+        format!("{} {:?} {:?}", input.name, input.username, input.nickname)
+    }
+    fn user(
+        &self,
+        by: UserBy,
+        #[graphql(deprecation = "always true")] active: Option<bool>,
+    ) -> String {
+        let by = match by {
+            UserBy::Id(id) => format!("id {}", id),
+            UserBy::Email(email) => format!("email {}", email),
+        };
+        format!("{} {:?}", by, active)
+    }
+}
+
+#[derive(App)]
+struct App(Query);
+
+#[tokio::test]
+async fn test_deprecation() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Query {
+      createUser(input: UserInput!): String!
+      user(by: UserBy!, active: Boolean @deprecated(reason: "always true")): String!
+    }
+
+    input UserBy @oneOf {
+      id: String
+      email: String @deprecated(reason: "use id")
+    }
+
+    input UserInput {
+      name: String!
+      username: String @deprecated(reason: "use name")
+      nickname: String @deprecated
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Indicates that an Input Object is a OneOf Input Object (and thus requires exactly one of its field be provided)"
+    directive @oneOf on INPUT_OBJECT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            createUser(input: { name: "foo", username: "bar" })
+            user(by: { email: "foo@example.com" }, active: true)
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "createUser": "foo Some(\"bar\") None",
+            "user": "email foo@example.com Some(true)",
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_deprecation_with_default() {
+    #[derive(InputObject)]
+    struct DefaultInput {
+        #[graphql(default = "anonymous", deprecation)]
+        name: String,
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn create(&self, input: DefaultInput) -> String {
+            input.name
+        }
+        fn user(&self, #[graphql(default, deprecation)] id: String) -> String {
+            id
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    input DefaultInput {
+      name: String! = "anonymous" @deprecated
+    }
+
+    type Query {
+      create(input: DefaultInput!): String!
+      user(id: String! = "" @deprecated): String!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            create(input: {})
+            user
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "create": "anonymous",
+            "user": "",
+        })
+    );
+}
@@ -0,0 +1,173 @@
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::InputObject;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::Scalar;
+use dynamic_graphql::ScalarValue;
+use dynamic_graphql::Value;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(InputObject)]
+struct Pagination {
+    /// Number of items to skip
+    offset: Option<i32>,
+    limit: i32,
+}
+
+#[derive(InputObject)]
+struct Sorting {
+    sort_by: Option<String>,
+    #[graphql(flatten)]
+    pagination: Pagination,
+}
+
+#[derive(InputObject)]
+struct SearchInput {
+    query: String,
+    #[graphql(flatten)]
+    sorting: Sorting,
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn search(&self, input: SearchInput) -> String {
+        format!(
+            "{} sort: {:?}, offset: {:?}, limit: {}",
+            input.query,
+            input.sorting.sort_by,
+            input.sorting.pagination.offset,
+            input.sorting.pagination.limit
+        )
+    }
+}
+
+#[derive(App)]
+struct App(Query);
+
+#[tokio::test]
+async fn test_flatten() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Query {
+      search(input: SearchInput!): String!
+    }
+
+    input SearchInput {
+      query: String!
+      sortBy: String
+      "Number of items to skip" offset: Int
+      limit: Int!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            search(input: { query: "foo", sortBy: "name", limit: 10 })
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "search": "foo sort: Some(\"name\"), offset: None, limit: 10",
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_flatten_collect_errors() {
+    #[derive(Scalar)]
+    struct Limit(i32);
+
+    impl ScalarValue for Limit {
+        fn from_value(value: Value) -> dynamic_graphql::Result<Self> {
+            match value {
+                Value::Number(n) if n.as_i64().is_some_and(|n| (1..=100).contains(&n)) => {
+                    Ok(Limit(n.as_i64().unwrap() as i32))
+                }
+                _ => Err(dynamic_graphql::Error::new("invalid limit")),
+            }
+        }
+
+        fn to_value(&self) -> Value {
+            Value::Number(self.0.into())
+        }
+    }
+
+    #[derive(InputObject)]
+    #[graphql(collect_errors)]
+    struct LimitInput {
+        limit: Limit,
+        max: Limit,
+    }
+
+    #[derive(InputObject)]
+    #[graphql(collect_errors)]
+    struct LimitedSearchInput {
+        query: String,
+        #[graphql(flatten)]
+        limits: LimitInput,
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn search(&self, input: LimitedSearchInput) -> String {
+            format!(
+                "{} {} {}",
+                input.query, input.limits.limit.0, input.limits.max.0
+            )
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            search(input: { query: "foo", limit: 0, max: 1000 })
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(
+        serde_json::to_value(&res.errors[0].extensions).unwrap(),
+        serde_json::json!({
+            "errors": [
+                { "path": "input.limit", "message": r#"Failed to parse "Limit": invalid limit"# },
+                { "path": "input.max", "message": r#"Failed to parse "Limit": invalid limit"# },
+            ],
+        })
+    );
+}
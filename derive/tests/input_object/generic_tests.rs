@@ -0,0 +1,153 @@
+use std::borrow::Cow;
+
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::InputObject;
+use dynamic_graphql::OneOfInput;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use dynamic_graphql::internal::FromValue;
+use dynamic_graphql::internal::GetInputTypeRef;
+use dynamic_graphql::internal::InputTypeName;
+use dynamic_graphql::internal::TypeName;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(InputObject)]
+struct Filter<T>
+where
+    T: InputTypeName + FromValue + GetInputTypeRef + 'static,
+{
+    eq: Option<T>,
+    any: Option<Vec<T>>,
+}
+
+#[derive(OneOfInput)]
+enum Range<T>
+where
+    T: InputTypeName + FromValue + GetInputTypeRef + 'static,
+{
+    Above(T),
+    Below(T),
+}
+
+#[derive(InputObject)]
+#[graphql(get_type_name)]
+struct Custom<T>
+where
+    T: InputTypeName + FromValue + GetInputTypeRef + 'static,
+{
+    value: T,
+}
+
+impl<T> TypeName for Custom<T>
+where
+    T: InputTypeName + FromValue + GetInputTypeRef + 'static,
+{
+    fn get_type_name() -> Cow<'static, str> {
+        format!("Custom{}", T::get_input_type_name()).into()
+    }
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn names(&self, filter: Filter<String>) -> String {
+        match (filter.eq, filter.any) {
+            (Some(eq), _) => format!("eq {}", eq),
+            (None, Some(any)) => format!("any {}", any.join(",")),
+            (None, None) => "all".to_string(),
+        }
+    }
+    fn ages(&self, filter: Filter<i32>, range: Option<Range<i32>>) -> String {
+        let range = match range {
+            Some(Range::Above(value)) => format!("above {}", value),
+            Some(Range::Below(value)) => format!("below {}", value),
+            None => "any".to_string(),
+        };
+        format!("eq {:?}, {}", filter.eq, range)
+    }
+    fn custom(&self, input: Custom<bool>) -> bool {
+        input.value
+    }
+}
+
+#[derive(App)]
+struct App(Query);
+
+#[tokio::test]
+async fn test_generic_input() {
+    assert_eq!(
+        <Filter<String> as TypeName>::get_type_name(),
+        "StringFilter"
+    );
+    assert_eq!(<Range<i32> as TypeName>::get_type_name(), "IntRange");
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    input CustomBoolean {
+      value: Boolean!
+    }
+
+    input IntFilter {
+      eq: Int
+      any: [Int!]
+    }
+
+    input IntRange @oneOf {
+      above: Int
+      below: Int
+    }
+
+    type Query {
+      names(filter: StringFilter!): String!
+      ages(filter: IntFilter!, range: IntRange): String!
+      custom(input: CustomBoolean!): Boolean!
+    }
+
+    input StringFilter {
+      eq: String
+      any: [String!]
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Indicates that an Input Object is a OneOf Input Object (and thus requires exactly one of its field be provided)"
+    directive @oneOf on INPUT_OBJECT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            names(filter: { any: ["a", "b"] })
+            ages(filter: { eq: 3 }, range: { above: 1 })
+            custom(input: { value: true })
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "names": "any a,b",
+            "ages": "eq Some(3), above 1",
+            "custom": true,
+        })
+    );
+}
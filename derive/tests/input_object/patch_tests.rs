@@ -0,0 +1,186 @@
+use std::sync::Mutex;
+
+use dynamic_graphql::App;
+use dynamic_graphql::Context;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::InputObject;
+use dynamic_graphql::MaybeUndefined;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(SimpleObject, Clone, Debug, PartialEq)]
+struct User {
+    name: String,
+    nickname: Option<String>,
+    age: Option<i32>,
+    email: Option<String>,
+    score: i32,
+}
+
+#[derive(InputObject)]
+#[graphql(patch_for = "User")]
+struct UserPatch {
+    name: Option<String>,
+    nickname: MaybeUndefined<String>,
+    age: MaybeUndefined<i32>,
+    email: Option<String>,
+    #[graphql(skip)]
+    #[allow(dead_code)]
+    score: Option<i32>,
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn update_user(&self, ctx: &Context<'_>, patch: UserPatch) -> User {
+        let mut user = ctx.data_unchecked::<Mutex<User>>().lock().unwrap();
+        patch.apply_to(&mut user);
+        user.clone()
+    }
+}
+
+#[derive(App)]
+struct App(Query);
+
+fn create_user() -> User {
+    User {
+        name: "foo".to_string(),
+        nickname: Some("bar".to_string()),
+        age: Some(20),
+        email: Some("foo@example.com".to_string()),
+        score: 1,
+    }
+}
+
+#[test]
+fn test_apply_to() {
+    let mut user = create_user();
+    UserPatch {
+        name: None,
+        nickname: MaybeUndefined::Null,
+        age: MaybeUndefined::Undefined,
+        email: None,
+        score: Some(100),
+    }
+    .apply_to(&mut user);
+    assert_eq!(
+        user,
+        User {
+            name: "foo".to_string(),
+            nickname: None,
+            age: Some(20),
+            email: Some("foo@example.com".to_string()),
+            score: 1,
+        }
+    );
+
+    UserPatch {
+        name: Some("baz".to_string()),
+        nickname: MaybeUndefined::Value("qux".to_string()),
+        age: MaybeUndefined::Value(30),
+        email: Some("baz@example.com".to_string()),
+        score: None,
+    }
+    .apply_to(&mut user);
+    assert_eq!(
+        user,
+        User {
+            name: "baz".to_string(),
+            nickname: Some("qux".to_string()),
+            age: Some(30),
+            email: Some("baz@example.com".to_string()),
+            score: 1,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_patch() {
+    let schema = App::create_schema()
+        .data(Mutex::new(create_user()))
+        .finish()
+        .unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Query {
+      updateUser(patch: UserPatch!): User!
+    }
+
+    type User {
+      name: String!
+      nickname: String
+      age: Int
+      email: String
+      score: Int!
+    }
+
+    input UserPatch {
+      name: String
+      nickname: String
+      age: Int
+      email: String
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            updateUser(patch: { name: null, nickname: null, email: null }) {
+                name
+                nickname
+                age
+                email
+                score
+            }
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "updateUser": { "name": "foo", "nickname": null, "age": 20, "email": "foo@example.com", "score": 1 },
+        })
+    );
+
+    let query = r#"
+        query {
+            updateUser(patch: { name: "baz", age: 30 }) {
+                name
+                nickname
+                age
+            }
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "updateUser": { "name": "baz", "nickname": null, "age": 30 },
+        })
+    );
+}
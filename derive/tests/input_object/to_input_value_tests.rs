@@ -0,0 +1,239 @@
+use dynamic_graphql::App;
+use dynamic_graphql::Enum;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::ID;
+use dynamic_graphql::InputObject;
+use dynamic_graphql::MaybeUndefined;
+use dynamic_graphql::OneOfInput;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::Scalar;
+use dynamic_graphql::ScalarValue;
+use dynamic_graphql::Value;
+use dynamic_graphql::Variables;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use dynamic_graphql::internal::FromValue;
+use dynamic_graphql::internal::GetInputTypeRef;
+use dynamic_graphql::internal::InputTypeName;
+use dynamic_graphql::internal::ToInputValue;
+use dynamic_graphql::value;
+
+#[derive(Scalar, Debug, Clone)]
+struct Zip(String);
+
+impl ScalarValue for Zip {
+    fn from_value(value: Value) -> dynamic_graphql::Result<Self> {
+        match value {
+            Value::String(value) => Ok(Zip(value)),
+            _ => Err(dynamic_graphql::Error::new("invalid zip")),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.0.clone())
+    }
+}
+
+#[derive(Enum, Debug, Clone, Copy)]
+enum Role {
+    Admin,
+    #[graphql(name = "GUEST")]
+    Visitor,
+}
+
+#[derive(InputObject, Debug, Clone)]
+struct Paging {
+    offset: i32,
+    limit: Option<u8>,
+}
+
+#[derive(InputObject, Debug, Clone)]
+struct AddressInput {
+    zip: Zip,
+    #[graphql(name = "town")]
+    city: String,
+}
+
+#[derive(InputObject, Debug, Clone)]
+struct UserInput {
+    id: ID,
+    first_name: String,
+    role: Role,
+    score: f64,
+    active: bool,
+    nickname: Option<String>,
+    bio: MaybeUndefined<String>,
+    tags: Vec<String>,
+    addresses: Option<Vec<AddressInput>>,
+    #[graphql(skip)]
+    #[allow(dead_code)]
+    internal: i32,
+    #[graphql(flatten)]
+    paging: Paging,
+}
+
+#[derive(InputObject, Debug, Clone)]
+struct Filter<T>
+where
+    T: InputTypeName + FromValue + GetInputTypeRef + 'static,
+{
+    eq: Option<T>,
+}
+
+#[derive(OneOfInput, Debug, Clone)]
+enum UserBy {
+    Id(ID),
+    Address(AddressInput),
+    Name { first_name: String, role: Role },
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn user(&self, input: UserInput) -> String {
+        format!("{:?}", input)
+    }
+    fn find(&self, by: UserBy) -> String {
+        format!("{:?}", by)
+    }
+    fn filter(&self, filter: Filter<i32>) -> String {
+        format!("{:?}", filter)
+    }
+}
+
+#[derive(App)]
+struct App(Query);
+
+fn create_user() -> UserInput {
+    UserInput {
+        id: ID("1".to_string()),
+        first_name: "foo".to_string(),
+        role: Role::Visitor,
+        score: 1.5,
+        active: true,
+        nickname: None,
+        bio: MaybeUndefined::Undefined,
+        tags: vec!["a".to_string(), "b".to_string()],
+        addresses: Some(vec![AddressInput {
+            zip: Zip("12345".to_string()),
+            city: "bar".to_string(),
+        }]),
+        internal: 0,
+        paging: Paging {
+            offset: 10,
+            limit: Some(20),
+        },
+    }
+}
+
+#[test]
+fn test_to_input_value() {
+    assert_eq!(
+        create_user().to_input_value(),
+        value!({
+            "id": "1",
+            "firstName": "foo",
+            "role": Value::Enum(dynamic_graphql::Name::new("GUEST")),
+            "score": 1.5,
+            "active": true,
+            "nickname": null,
+            "tags": ["a", "b"],
+            "addresses": [{ "zip": "12345", "town": "bar" }],
+            "offset": 10,
+            "limit": 20,
+        })
+    );
+
+    let user = UserInput {
+        bio: MaybeUndefined::Null,
+        ..create_user()
+    };
+    let Value::Object(fields) = user.to_input_value() else {
+        panic!("expected an object");
+    };
+    assert_eq!(fields.get("bio"), Some(&Value::Null));
+
+    assert_eq!(
+        UserBy::Name {
+            first_name: "foo".to_string(),
+            role: Role::Admin,
+        }
+        .to_input_value(),
+        value!({
+            "name": {
+                "firstName": "foo",
+                "role": Value::Enum(dynamic_graphql::Name::new("ADMIN")),
+            },
+        })
+    );
+}
+
+async fn round_trip(query: &str, name: &str, value: Value) -> String {
+    let schema = App::create_schema().finish().unwrap();
+    let variables = Variables::from_value(value!({ name: value }));
+    let req = dynamic_graphql::Request::new(query)
+        .variables(variables)
+        .root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors, vec![]);
+    let data = res.data.into_json().unwrap();
+    data.as_object()
+        .unwrap()
+        .values()
+        .next()
+        .unwrap()
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+#[tokio::test]
+async fn test_round_trip() {
+    let query = "query($input: UserInput!) { user(input: $input) }";
+    for user in [
+        create_user(),
+        UserInput {
+            nickname: Some("baz".to_string()),
+            bio: MaybeUndefined::Null,
+            addresses: None,
+            ..create_user()
+        },
+        UserInput {
+            bio: MaybeUndefined::Value("qux".to_string()),
+            role: Role::Admin,
+            paging: Paging {
+                offset: 0,
+                limit: None,
+            },
+            ..create_user()
+        },
+    ] {
+        let res = round_trip(query, "input", user.to_input_value()).await;
+        assert_eq!(res, format!("{:?}", user));
+    }
+
+    let query = "query($by: UserBy!) { find(by: $by) }";
+    for by in [
+        UserBy::Id(ID("1".to_string())),
+        UserBy::Address(AddressInput {
+            zip: Zip("12345".to_string()),
+            city: "bar".to_string(),
+        }),
+        UserBy::Name {
+            first_name: "foo".to_string(),
+            role: Role::Admin,
+        },
+    ] {
+        let res = round_trip(query, "by", by.to_input_value()).await;
+        assert_eq!(res, format!("{:?}", by));
+    }
+
+    let query = "query($filter: IntFilter!) { filter(filter: $filter) }";
+    for filter in [Filter { eq: Some(1) }, Filter { eq: None }] {
+        let res = round_trip(query, "filter", filter.to_input_value()).await;
+        assert_eq!(res, format!("{:?}", filter));
+    }
+}
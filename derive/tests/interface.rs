@@ -2,7 +2,11 @@ mod schema_utils;
 mod interface {
     mod as_value_tests;
     mod async_test;
+    mod conformance_tests;
+    mod default_fields_tests;
+    mod downcast_tests;
     mod implementation_tests;
+    mod inheritance_tests;
     mod interface_tests;
     mod list_tests;
     mod output_types_tests;
@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use dynamic_graphql::App;
+use dynamic_graphql::Context;
 use dynamic_graphql::FieldValue;
 use dynamic_graphql::Instance;
 use dynamic_graphql::Interface;
@@ -102,3 +103,157 @@ async fn test_async_trait() {
         })
     );
 }
+
+#[tokio::test]
+async fn test_native_async() {
+    struct Greeting(String);
+
+    #[Interface]
+    trait Foo {
+        fn sync_value(&self) -> String;
+        async fn async_value(&self) -> String;
+        async fn greet(&self, #[graphql(ctx)] ctx: &Context<'_>, name: String) -> String;
+        async fn default_value(&self) -> String {
+            "default_value".to_string()
+        }
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(implements(Foo))]
+    struct FooValue {
+        #[graphql(skip)]
+        value: String,
+    }
+
+    impl Foo for FooValue {
+        fn sync_value(&self) -> String {
+            "sync_value".to_string()
+        }
+
+        async fn async_value(&self) -> String {
+            self.value.clone()
+        }
+
+        async fn greet(&self, ctx: &Context<'_>, name: String) -> String {
+            let greeting = ctx.data_unchecked::<Greeting>();
+            format!("{} {}", greeting.0, name)
+        }
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        async fn foo(&self) -> Instance<'static, dyn Foo> {
+            Instance::new_owned(FooValue {
+                value: "async_value".to_string(),
+            })
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query, FooValue);
+
+    let schema = App::create_schema()
+        .data(Greeting("Hello".to_string()))
+        .finish()
+        .unwrap();
+    let sdl = schema.sdl();
+
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    interface Foo {
+      syncValue: String!
+      asyncValue: String!
+      greet(name: String!): String!
+      defaultValue: String!
+    }
+
+    type FooValue implements Foo {
+      syncValue: String!
+      asyncValue: String!
+      greet(name: String!): String!
+      defaultValue: String!
+    }
+
+    type Query {
+      foo: Foo!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+
+        query {
+            foo {
+                syncValue
+                asyncValue
+                greet(name: "world")
+                defaultValue
+            }
+        }
+
+    "#;
+
+    let root = Query;
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(root));
+    let res = schema.execute(req).await;
+
+    assert_eq!(
+        res.data.into_json().unwrap(),
+        serde_json::json!({
+            "foo": {
+                "syncValue": "sync_value",
+                "asyncValue": "async_value",
+                "greet": "Hello world",
+                "defaultValue": "default_value"
+            }
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_native_async_on_dyn() {
+    #[Interface]
+    trait Foo {
+        async fn async_value(&self) -> String;
+        async fn echo(&self, prefix: &str, value: String) -> String;
+        async fn default_value(&self) -> String {
+            "default_value".to_string()
+        }
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(implements(Foo))]
+    struct FooValue {
+        #[graphql(skip)]
+        value: String,
+    }
+
+    impl Foo for FooValue {
+        async fn async_value(&self) -> String {
+            self.value.clone()
+        }
+
+        async fn echo(&self, prefix: &str, value: String) -> String {
+            format!("{}{}", prefix, value)
+        }
+    }
+
+    let foo: Box<dyn Foo> = Box::new(FooValue {
+        value: "async_value".to_string(),
+    });
+    assert_eq!(foo.async_value_boxed().await, "async_value");
+    assert_eq!(foo.echo_boxed("> ", "value".to_string()).await, "> value");
+    assert_eq!(foo.default_value_boxed().await, "default_value");
+}
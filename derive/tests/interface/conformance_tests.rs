@@ -0,0 +1,167 @@
+use dynamic_graphql::App;
+use dynamic_graphql::Instance;
+use dynamic_graphql::Interface;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::internal::Registry;
+
+#[Interface]
+trait Node {
+    fn id(&self) -> String;
+}
+
+#[derive(SimpleObject)]
+#[graphql(mark(Node))]
+struct User {
+    id: String,
+}
+
+#[test]
+fn test_valid_implementations() {
+    #[Interface]
+    trait Entity {
+        fn id(&self) -> Option<String>;
+        fn owner(&self) -> Option<Instance<'_, dyn Node>>;
+        fn nodes(&self, first: Option<i32>) -> Vec<Instance<'_, dyn Node>>;
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(mark(Entity))]
+    struct Post;
+
+    #[ResolvedObjectFields]
+    impl Post {
+        // non-null is a subtype of nullable
+        fn id(&self) -> String {
+            "1".to_string()
+        }
+        // an object is a subtype of the interfaces it implements
+        fn owner(&self) -> User {
+            User {
+                id: "1".to_string(),
+            }
+        }
+        // additional optional arguments are allowed
+        fn nodes(&self, first: Option<i32>, _after: Option<String>) -> Vec<User> {
+            let _ = first;
+            vec![]
+        }
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    struct Query {
+        post: Post,
+    }
+
+    #[derive(App)]
+    struct App(Query, Post);
+
+    let registry = Registry::new().register::<App>();
+    assert_eq!(registry.check_interfaces(), Ok(()));
+}
+
+#[test]
+fn test_reports_all_errors() {
+    #[Interface]
+    trait Entity {
+        fn id(&self) -> String;
+        fn name(&self) -> String;
+        fn owner(&self) -> Instance<'_, dyn Node>;
+        fn search(&self, query: String, limit: i32) -> Vec<String>;
+        fn parent(&self) -> Option<String>;
+    }
+
+    #[derive(SimpleObject)]
+    struct Other {
+        id: String,
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(mark(Entity))]
+    struct Post;
+
+    #[ResolvedObjectFields]
+    impl Post {
+        fn id(&self) -> Option<String> {
+            None
+        }
+        fn owner(&self) -> Other {
+            Other {
+                id: "1".to_string(),
+            }
+        }
+        fn search(&self, query: String, limit: String) -> Vec<String> {
+            vec![query, limit]
+        }
+        fn parent(&self, required: String) -> Option<String> {
+            Some(required)
+        }
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    struct Query {
+        post: Post,
+    }
+
+    #[derive(App)]
+    struct App(Query, Post);
+
+    let registry = Registry::new().register::<App>();
+    let post = format!("\"Post\" (`{}`)", std::any::type_name::<Post>());
+    let errors = registry
+        .check_interfaces()
+        .unwrap_err()
+        .into_iter()
+        .map(|err| err.replace(&post, "Post"))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        errors,
+        vec![
+            r#"Post marked with `Entity` does not implement interface "Entity" (`Entity`): field "id" returns "String", expected "String!" or a subtype"#,
+            r#"Post marked with `Entity` does not implement interface "Entity" (`Entity`): missing field "name: String!""#,
+            r#"Post marked with `Entity` does not implement interface "Entity" (`Entity`): field "owner" returns "Other!", expected "Node!" or a subtype"#,
+            r#"Post marked with `Entity` does not implement interface "Entity" (`Entity`): argument "search.limit" accepts "String!", expected "Int!""#,
+            r#"Post marked with `Entity` does not implement interface "Entity" (`Entity`): field "parent" has additional required argument "required: String!""#,
+        ]
+    );
+}
+
+#[test]
+fn test_missing_argument() {
+    #[Interface]
+    trait Searchable {
+        fn search(&self, query: String) -> Vec<String>;
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(mark(Searchable))]
+    struct Post;
+
+    #[ResolvedObjectFields]
+    impl Post {
+        fn search(&self) -> Vec<String> {
+            vec![]
+        }
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    struct Query {
+        post: Post,
+    }
+
+    #[derive(App)]
+    struct App(Query, Post);
+
+    let registry = Registry::new().register::<App>();
+    let errors = registry.check_interfaces().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(
+        errors[0].ends_with(r#"field "search" is missing argument "query: String!""#),
+        "{}",
+        errors[0]
+    );
+}
@@ -0,0 +1,188 @@
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::Instance;
+use dynamic_graphql::Interface;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+#[tokio::test]
+async fn test_implements_with_default_method() {
+    #[Interface]
+    trait Node {
+        fn id(&self) -> String {
+            "default_id".to_string()
+        }
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(implements(Node))]
+    struct Foo {
+        foo: String,
+    }
+
+    impl Node for Foo {}
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        async fn node(&self) -> Instance<'static, dyn Node> {
+            Instance::new_owned(Foo {
+                foo: "foo".to_string(),
+            })
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query, Foo);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            node {
+                id
+                ... on Foo {
+                    foo
+                }
+            }
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "node": { "id": "default_id", "foo": "foo" },
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_mark_with_interface_default() {
+    #[Interface]
+    trait Node {
+        fn id(&self) -> String {
+            "default_id".to_string()
+        }
+        fn name(&self) -> String;
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(mark(Node), interface_default(id))]
+    struct Foo {
+        name: String,
+    }
+
+    impl Node for Foo {
+        fn name(&self) -> String {
+            "unused".to_string()
+        }
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(mark(Node))]
+    #[graphql(interface_default(Node::id))]
+    struct Bar;
+
+    #[ResolvedObjectFields]
+    impl Bar {
+        fn name(&self) -> String {
+            "bar".to_string()
+        }
+    }
+
+    impl Node for Bar {
+        fn id(&self) -> String {
+            "bar_id".to_string()
+        }
+        fn name(&self) -> String {
+            "unused".to_string()
+        }
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        async fn nodes(&self) -> Vec<Instance<'static, dyn Node>> {
+            vec![
+                Instance::new_owned(Foo {
+                    name: "foo".to_string(),
+                }),
+                Instance::new_owned(Bar),
+            ]
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query, Foo, Bar);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Bar implements Node {
+      name: String!
+      id: String!
+    }
+
+    type Foo implements Node {
+      name: String!
+      id: String!
+    }
+
+    interface Node {
+      id: String!
+      name: String!
+    }
+
+    type Query {
+      nodes: [Node!]!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            nodes {
+                id
+                name
+            }
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "nodes": [
+                { "id": "default_id", "name": "foo" },
+                { "id": "bar_id", "name": "bar" },
+            ],
+        })
+    );
+}
@@ -0,0 +1,177 @@
+use std::sync::Arc;
+
+use dynamic_graphql::AnyObject;
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::Instance;
+use dynamic_graphql::Interface;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+#[test]
+fn test_type_name_and_downcast() {
+    #[Interface]
+    trait Node {
+        fn id(&self) -> String;
+    }
+
+    #[derive(SimpleObject, Debug, PartialEq)]
+    #[graphql(mark(Node))]
+    struct Foo {
+        id: String,
+    }
+
+    #[derive(SimpleObject, Debug, PartialEq)]
+    #[graphql(name = "OtherBar", mark(Node))]
+    struct Bar {
+        id: String,
+    }
+
+    let foo: Instance<dyn Node> = Instance::new_owned(Foo {
+        id: "foo".to_string(),
+    });
+    assert_eq!(foo.type_name(), "Foo");
+    assert!(foo.is::<Foo>());
+    assert!(!foo.is::<Bar>());
+    assert_eq!(
+        foo.downcast_ref::<Foo>().map(|foo| foo.id.as_str()),
+        Some("foo")
+    );
+    assert_eq!(foo.downcast_ref::<Bar>(), None);
+
+    let foo = foo.downcast::<Bar>().unwrap_err();
+    assert_eq!(
+        foo.downcast::<Foo>().ok(),
+        Some(Foo {
+            id: "foo".to_string()
+        })
+    );
+
+    let bar = Bar {
+        id: "bar".to_string(),
+    };
+    let borrowed: Instance<dyn Node> = Instance::new_borrowed(&bar);
+    assert_eq!(borrowed.type_name(), "OtherBar");
+    assert_eq!(borrowed.downcast_ref::<Bar>(), Some(&bar));
+    // borrowed values can't be moved out
+    let borrowed = borrowed.downcast::<Bar>().unwrap_err();
+    assert!(borrowed.is::<Bar>());
+}
+
+#[test]
+fn test_from_arc() {
+    #[Interface]
+    trait Node {
+        fn id(&self) -> String;
+    }
+
+    #[derive(SimpleObject, Clone, Debug, PartialEq)]
+    #[graphql(mark(Node))]
+    struct Foo {
+        id: String,
+    }
+
+    let foo = Arc::new(Foo {
+        id: "foo".to_string(),
+    });
+    let shared = foo.clone();
+
+    let instance: Instance<dyn Node> = foo.into();
+    assert_eq!(instance.type_name(), "Foo");
+    assert_eq!(instance.downcast_ref::<Foo>(), Some(shared.as_ref()));
+}
+
+#[tokio::test]
+async fn test_from_boxed_trait_object() {
+    #[Interface]
+    trait Node: AnyObject {
+        fn id(&self) -> String;
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(implements(Node))]
+    struct Foo {
+        foo: String,
+    }
+
+    impl Node for Foo {
+        fn id(&self) -> String {
+            "foo".to_string()
+        }
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(implements(Node))]
+    struct Bar {
+        bar: String,
+    }
+
+    impl Node for Bar {
+        fn id(&self) -> String {
+            "bar".to_string()
+        }
+    }
+
+    fn build_nodes() -> Vec<Box<dyn Node>> {
+        vec![
+            Box::new(Foo {
+                foo: "FOO".to_string(),
+            }),
+            Box::new(Bar {
+                bar: "BAR".to_string(),
+            }),
+        ]
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        async fn nodes(&self) -> Vec<Instance<'static, dyn Node>> {
+            build_nodes().into_iter().map(Instance::from).collect()
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query, Foo, Bar);
+
+    let names = build_nodes()
+        .into_iter()
+        .map(|node| Instance::<dyn Node>::from(node).type_name().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["Foo", "Bar"]);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            nodes {
+                id
+                ... on Foo {
+                    foo
+                }
+                ... on Bar {
+                    bar
+                }
+            }
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "nodes": [
+                { "id": "foo", "foo": "FOO" },
+                { "id": "bar", "bar": "BAR" },
+            ],
+        })
+    );
+}
@@ -116,6 +116,9 @@ fn test_schema_simple_object_with_implement() {
 }
 
 #[test]
+#[should_panic(
+    expected = r#"marked with `Node` does not implement interface "Node" (`Node`): missing field "theId: String!""#
+)]
 fn test_schema_simple_object_with_error() {
     #[Interface]
     trait Node {
@@ -137,13 +140,7 @@ fn test_schema_simple_object_with_error() {
     #[derive(App)]
     struct App(Query, FooNode);
 
-    let schema = App::create_schema().finish();
-
-    assert!(schema.is_err());
-    assert_eq!(
-        schema.err().unwrap().to_string(),
-        r#"Object "FooNode" requires field "theId" defined by interface "Node""#
-    );
+    let _ = App::create_schema();
 }
 
 #[test]
@@ -263,6 +260,9 @@ fn test_schema_resolved_object_with_implement() {
 }
 
 #[test]
+#[should_panic(
+    expected = r#"marked with `Node` does not implement interface "Node" (`Node`): missing field "theId: String!""#
+)]
 fn test_schema_resolved_object_with_error() {
     #[Interface]
     trait Node {
@@ -289,11 +289,5 @@ fn test_schema_resolved_object_with_error() {
     #[derive(App)]
     struct App(Query, FooNode);
 
-    let schema = App::create_schema().finish();
-
-    assert!(schema.is_err());
-    assert_eq!(
-        schema.err().unwrap().to_string(),
-        r#"Object "FooNode" requires field "theId" defined by interface "Node""#
-    );
+    let _ = App::create_schema();
 }
@@ -0,0 +1,425 @@
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::Instance;
+use dynamic_graphql::Interface;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+#[test]
+fn test_schema_interface_extends_interface() {
+    #[Interface]
+    trait Node {
+        fn id(&self) -> String;
+    }
+
+    #[Interface]
+    #[graphql(extends(Node))]
+    trait Entity: Node + Send + Sync {
+        fn name(&self) -> String;
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    struct Query {
+        foo: String,
+    }
+
+    #[derive(App)]
+    struct App(Query, Instance<'static, dyn Entity>);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    interface Entity {
+      name: String!
+      id: String!
+    }
+
+    interface Node {
+      id: String!
+    }
+
+    type Query {
+      foo: String!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+}
+
+#[test]
+fn test_schema_supertraits_are_not_extended() {
+    #[Interface]
+    trait Node {
+        fn id(&self) -> String;
+    }
+
+    #[Interface]
+    trait Entity: Node + std::fmt::Debug {
+        fn name(&self) -> String;
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    struct Query {
+        foo: String,
+    }
+
+    #[derive(App)]
+    struct App(Query, Instance<'static, dyn Entity>);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    interface Entity {
+      name: String!
+    }
+
+    type Query {
+      foo: String!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+}
+
+#[test]
+fn test_schema_transitive_interfaces() {
+    #[Interface]
+    trait Node {
+        fn id(&self) -> String;
+    }
+
+    #[Interface]
+    #[graphql(extends(Node))]
+    trait Entity: Node {
+        fn name(&self) -> String;
+    }
+
+    #[Interface]
+    #[graphql(extends(Node))]
+    trait Named: Node {
+        fn title(&self) -> String;
+    }
+
+    #[Interface]
+    #[graphql(extends(Entity), extends(Named))]
+    trait Person: Entity + Named {
+        fn age(&self) -> i32;
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    struct Query {
+        foo: String,
+    }
+
+    #[derive(App)]
+    struct App(Query, Instance<'static, dyn Person>);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    interface Entity {
+      name: String!
+      id: String!
+    }
+
+    interface Named {
+      title: String!
+      id: String!
+    }
+
+    interface Node {
+      id: String!
+    }
+
+    interface Person {
+      age: Int!
+      name: String!
+      id: String!
+      title: String!
+    }
+
+    type Query {
+      foo: String!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn test_implements_extended_interface() {
+    #[Interface]
+    trait Node {
+        fn id(&self) -> String;
+    }
+
+    #[Interface]
+    #[graphql(extends(Node))]
+    trait Entity: Node {
+        fn name(&self) -> String;
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(implements(Entity))]
+    struct Foo {
+        other: String,
+    }
+
+    impl Node for Foo {
+        fn id(&self) -> String {
+            "foo".to_string()
+        }
+    }
+
+    impl Entity for Foo {
+        fn name(&self) -> String {
+            "Foo".to_string()
+        }
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        async fn entity(&self) -> Instance<'static, dyn Entity> {
+            Instance::new_owned(Foo {
+                other: "other".to_string(),
+            })
+        }
+        async fn node(&self) -> Instance<'static, dyn Node> {
+            self.entity().await.upcast()
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query, Foo);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    interface Entity {
+      name: String!
+      id: String!
+    }
+
+    type Foo implements Entity & Node {
+      other: String!
+      name: String!
+      id: String!
+    }
+
+    interface Node {
+      id: String!
+    }
+
+    type Query {
+      entity: Entity!
+      node: Node!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            entity {
+                id
+                name
+                ... on Foo {
+                    other
+                }
+            }
+            node {
+                id
+                ... on Entity {
+                    name
+                }
+            }
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "entity": { "id": "foo", "name": "Foo", "other": "other" },
+            "node": { "id": "foo", "name": "Foo" },
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_mark_extended_interface() {
+    #[Interface]
+    trait Node {
+        fn id(&self) -> String;
+    }
+
+    #[Interface]
+    #[graphql(extends(Node))]
+    trait Entity: Node {
+        fn name(&self) -> String;
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(mark(Entity))]
+    struct Foo {
+        id: String,
+        name: String,
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        async fn node(&self) -> Instance<'static, dyn Node> {
+            Instance::<dyn Entity>::new_owned(Foo {
+                id: "foo".to_string(),
+                name: "Foo".to_string(),
+            })
+            .upcast()
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query, Foo);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    interface Entity {
+      name: String!
+      id: String!
+    }
+
+    type Foo implements Entity & Node {
+      id: String!
+      name: String!
+    }
+
+    interface Node {
+      id: String!
+    }
+
+    type Query {
+      node: Node!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            node {
+                id
+                ... on Foo {
+                    name
+                }
+            }
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "node": { "id": "foo", "name": "Foo" },
+        })
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = r#"marked with `Entity` does not implement interface "Entity" (`Entity`): missing field "id: String!""#
+)]
+fn test_mark_extended_interface_missing_inherited_field() {
+    #[Interface]
+    trait Node {
+        fn id(&self) -> String;
+    }
+
+    #[Interface]
+    #[graphql(extends(Node))]
+    trait Entity: Node {
+        fn name(&self) -> String;
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(mark(Entity))]
+    struct Foo {
+        name: String,
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    struct Query {
+        foo: Foo,
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let _ = App::create_schema();
+}
@@ -1,4 +1,5 @@
 mod schema_utils;
 mod mutation {
+    mod errors_as_union_tests;
     mod mutation_tests;
 }
@@ -0,0 +1,187 @@
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::Mutation;
+use dynamic_graphql::MutationFields;
+use dynamic_graphql::MutationRoot;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::Union;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(SimpleObject)]
+struct CreateUserSuccess {
+    id: String,
+}
+
+#[derive(SimpleObject)]
+struct ValidationError {
+    field: String,
+    message: String,
+}
+
+#[derive(SimpleObject)]
+struct NotAuthorized {
+    message: String,
+}
+
+#[derive(Union)]
+enum CreateUserError {
+    ValidationError(ValidationError),
+    NotAuthorized(NotAuthorized),
+}
+
+#[derive(MutationRoot)]
+struct MutationRoot;
+
+#[derive(Mutation)]
+struct UserMutation(MutationRoot);
+
+#[MutationFields]
+impl UserMutation {
+    #[graphql(errors_as_union)]
+    fn create_user(name: String) -> Result<CreateUserSuccess, CreateUserError> {
+        match name.as_str() {
+            "" => Err(CreateUserError::ValidationError(ValidationError {
+                field: "name".to_string(),
+                message: "Name is required".to_string(),
+            })),
+            "root" => Err(CreateUserError::NotAuthorized(NotAuthorized {
+                message: "Not authorized".to_string(),
+            })),
+            _ => Ok(CreateUserSuccess { id: name }),
+        }
+    }
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    #[graphql(errors_as_union = "UserResult")]
+    async fn user(&self) -> Result<CreateUserSuccess, CreateUserError> {
+        Ok(CreateUserSuccess {
+            id: "1".to_string(),
+        })
+    }
+}
+
+#[derive(App)]
+struct App(Query, MutationRoot, UserMutation);
+
+#[test]
+fn test_schema() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    union CreateUserError = ValidationError | NotAuthorized
+
+    union CreateUserResult = CreateUserSuccess | ValidationError | NotAuthorized
+
+    type CreateUserSuccess {
+      id: String!
+    }
+
+    type MutationRoot {
+      createUser(name: String!): CreateUserResult!
+    }
+
+    type NotAuthorized {
+      message: String!
+    }
+
+    type Query {
+      user: UserResult!
+    }
+
+    union UserResult = CreateUserSuccess | ValidationError | NotAuthorized
+
+    type ValidationError {
+      field: String!
+      message: String!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+      mutation: MutationRoot
+    }
+"#);
+}
+
+#[tokio::test]
+async fn test_mutation() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        mutation {
+            ok: createUser(name: "foo") {
+                __typename
+                ... on CreateUserSuccess {
+                    id
+                }
+            }
+            invalid: createUser(name: "") {
+                __typename
+                ... on ValidationError {
+                    field
+                    message
+                }
+            }
+            forbidden: createUser(name: "root") {
+                __typename
+                ... on NotAuthorized {
+                    message
+                }
+            }
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    assert_eq!(
+        res.data.into_json().unwrap(),
+        serde_json::json!({
+            "ok": { "__typename": "CreateUserSuccess", "id": "foo" },
+            "invalid": {
+                "__typename": "ValidationError",
+                "field": "name",
+                "message": "Name is required",
+            },
+            "forbidden": { "__typename": "NotAuthorized", "message": "Not authorized" },
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_query() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            user {
+                ... on CreateUserSuccess {
+                    id
+                }
+            }
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(
+        res.data.into_json().unwrap(),
+        serde_json::json!({ "user": { "id": "1" } })
+    );
+}
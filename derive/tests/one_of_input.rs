@@ -1,5 +1,8 @@
 mod schema_utils;
 mod one_of_input {
     mod list_option;
+    mod paired_tests;
+    mod struct_variant_tests;
     mod tests;
+    mod unit_variant_tests;
 }
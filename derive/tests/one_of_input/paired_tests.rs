@@ -0,0 +1,140 @@
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::OneOfInput;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::Union;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+/// A shape
+#[derive(Union, OneOfInput)]
+#[graphql(input_name = "ShapeInput")]
+enum Shape {
+    /// A circle
+    Circle {
+        radius: f64,
+    },
+    #[graphql(type_name = "Rect", name = "rect")]
+    Rectangle {
+        width: f64,
+        #[graphql(output(name = "tall"))]
+        height: f64,
+        #[graphql(input(skip))]
+        rotated: bool,
+    },
+    Point,
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn rotate(&self, shape: Shape) -> Shape {
+        match shape {
+            Shape::Rectangle { width, height, .. } => Shape::Rectangle {
+                width: height,
+                height: width,
+                rotated: true,
+            },
+            shape => shape,
+        }
+    }
+}
+
+#[derive(App)]
+struct App(Query);
+
+#[tokio::test]
+async fn test_schema() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Query {
+      rotate(shape: ShapeInput!): Shape!
+    }
+
+    type Rect {
+      width: Float!
+      tall: Float!
+      rotated: Boolean!
+    }
+
+    "A shape"
+    union Shape = ShapeCircle | Rect | ShapePoint
+
+    "A circle"
+    type ShapeCircle {
+      radius: Float!
+    }
+
+    "A shape"
+    input ShapeInput @oneOf {
+      "A circle" circle: ShapeInputCircle
+      rect: ShapeInputRectangle
+      "Must be `true`." point: Boolean
+    }
+
+    input ShapeInputCircle {
+      radius: Float!
+    }
+
+    input ShapeInputRectangle {
+      width: Float!
+      height: Float!
+    }
+
+    type ShapePoint {
+      _: Boolean!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Indicates that an Input Object is a OneOf Input Object (and thus requires exactly one of its field be provided)"
+    directive @oneOf on INPUT_OBJECT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn test_query() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            circle: rotate(shape: { circle: { radius: 1.5 } }) {
+                __typename
+                ... on ShapeCircle { radius }
+            }
+            rect: rotate(shape: { rect: { width: 2.0, height: 3.0 } }) {
+                __typename
+                ... on Rect { width tall rotated }
+            }
+            point: rotate(shape: { point: true }) {
+                __typename
+            }
+        }
+    "#;
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors, vec![]);
+    assert_eq!(
+        res.data.into_json().unwrap(),
+        serde_json::json!({
+            "circle": { "__typename": "ShapeCircle", "radius": 1.5 },
+            "rect": { "__typename": "Rect", "width": 3.0, "tall": 2.0, "rotated": true },
+            "point": { "__typename": "ShapePoint" },
+        })
+    );
+}
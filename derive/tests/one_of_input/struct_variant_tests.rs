@@ -0,0 +1,113 @@
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::OneOfInput;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(OneOfInput)]
+enum ShapeInput {
+    /// A circle
+    Circle {
+        radius: f64,
+    },
+    #[graphql(type_name = "RectInput")]
+    Rectangle {
+        width: f64,
+        #[graphql(name = "tall")]
+        height: f64,
+        #[graphql(skip)]
+        rotated: bool,
+    },
+    Sides(i32),
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn shape(&self, input: ShapeInput) -> String {
+        match input {
+            ShapeInput::Circle { radius } => format!("circle {}", radius),
+            ShapeInput::Rectangle {
+                width,
+                height,
+                rotated,
+            } => format!("rectangle {}x{} rotated: {}", width, height, rotated),
+            ShapeInput::Sides(sides) => format!("sides {}", sides),
+        }
+    }
+}
+
+#[derive(App)]
+struct App(Query);
+
+#[tokio::test]
+async fn test_schema() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Query {
+      shape(input: ShapeInput!): String!
+    }
+
+    input RectInput {
+      width: Float!
+      tall: Float!
+    }
+
+    input ShapeInput @oneOf {
+      "A circle" circle: ShapeInputCircle
+      rectangle: RectInput
+      sides: Int
+    }
+
+    input ShapeInputCircle {
+      radius: Float!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Indicates that an Input Object is a OneOf Input Object (and thus requires exactly one of its field be provided)"
+    directive @oneOf on INPUT_OBJECT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn test_struct_variants() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            circle: shape(input: { circle: { radius: 1.5 } })
+            rectangle: shape(input: { rectangle: { width: 2, tall: 3 } })
+            sides: shape(input: { sides: 5 })
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "circle": "circle 1.5",
+            "rectangle": "rectangle 2x3 rotated: false",
+            "sides": "sides 5",
+        })
+    );
+}
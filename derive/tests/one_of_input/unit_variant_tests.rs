@@ -0,0 +1,135 @@
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::OneOfInput;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use dynamic_graphql::internal::ToInputValue;
+use dynamic_graphql::value;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(OneOfInput, Debug)]
+enum Sort {
+    /// The newest first
+    Newest,
+    #[graphql(name = "oldestFirst")]
+    Oldest,
+    ByField {
+        name: String,
+        descending: Option<bool>,
+    },
+    Random(i32),
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn sort(&self, sort: Sort) -> String {
+        format!("{:?}", sort)
+    }
+}
+
+#[derive(App)]
+struct App(Query);
+
+#[tokio::test]
+async fn test_schema() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Query {
+      sort(sort: Sort!): String!
+    }
+
+    input Sort @oneOf {
+      """
+        The newest first
+
+        Must be `true`.
+      """ newest: Boolean
+      "Must be `true`." oldestFirst: Boolean
+      byField: SortByField
+      random: Int
+    }
+
+    input SortByField {
+      name: String!
+      descending: Boolean
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Indicates that an Input Object is a OneOf Input Object (and thus requires exactly one of its field be provided)"
+    directive @oneOf on INPUT_OBJECT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn test_unit_variants() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            newest: sort(sort: { newest: true })
+            oldest: sort(sort: { oldestFirst: true })
+            byField: sort(sort: { byField: { name: "title" } })
+            random: sort(sort: { random: 42 })
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "newest": "Newest",
+            "oldest": "Oldest",
+            "byField": "ByField { name: \"title\", descending: None }",
+            "random": "Random(42)",
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_unit_variant_false() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            sort(sort: { newest: false })
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(
+        res.errors[0].message,
+        r#"Invalid value for argument "sort": Failed to parse "Sort": Invalid value for field "newest": Failed to parse "Boolean": only `true` is accepted"#
+    );
+}
+
+#[test]
+fn test_to_input_value() {
+    assert_eq!(Sort::Newest.to_input_value(), value!({ "newest": true }));
+    assert_eq!(
+        Sort::Oldest.to_input_value(),
+        value!({ "oldestFirst": true })
+    );
+}
@@ -1,7 +1,9 @@
 mod schema_utils;
 mod resolved_object {
     mod resolved_object_args_tests;
+    mod resolved_object_iter_tests;
     mod resolved_object_list_args_tests;
+    mod resolved_object_list_error_tests;
     mod resolved_object_list_tests;
     mod resolved_object_result_tests;
     mod resolved_object_tests;
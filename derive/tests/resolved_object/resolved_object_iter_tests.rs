@@ -0,0 +1,142 @@
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use futures_util::Stream;
+use futures_util::stream;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(thiserror::Error, Debug)]
+enum MyError {
+    #[error("Not found")]
+    NotFound,
+}
+
+#[derive(SimpleObject)]
+struct Item {
+    value: String,
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query {
+    items: Vec<Item>,
+}
+
+#[ResolvedObjectFields]
+impl Query {
+    fn numbers(&self) -> impl Iterator<Item = i32> {
+        1..=3
+    }
+    fn items(&self) -> impl Iterator<Item = &Item> + '_ {
+        self.items.iter()
+    }
+    fn maybe_numbers(&self) -> Option<impl Iterator<Item = Option<i32>>> {
+        Some([Some(1), None].into_iter())
+    }
+    async fn strings(&self) -> impl Stream<Item = String> + Send {
+        stream::iter(vec!["a".to_string(), "b".to_string()])
+    }
+    async fn fallible_strings(
+        &self,
+    ) -> Result<Option<impl Stream<Item = Result<String, MyError>> + Send>, MyError> {
+        Ok(Some(stream::iter(vec![
+            Ok("a".to_string()),
+            Err(MyError::NotFound),
+        ])))
+    }
+    #[graphql(partial_list)]
+    async fn partial_strings(&self) -> impl Stream<Item = Result<String, MyError>> + Send {
+        stream::iter(vec![Ok("a".to_string()), Err(MyError::NotFound)])
+    }
+}
+
+#[derive(App)]
+struct App(Query);
+
+#[test]
+fn test_schema() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Item {
+      value: String!
+    }
+
+    type Query {
+      numbers: [Int!]!
+      items: [Item!]!
+      maybeNumbers: [Int]
+      strings: [String!]!
+      fallibleStrings: [String!]
+      partialStrings: [String]!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn test_query() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            numbers
+            items {
+                value
+            }
+            maybeNumbers
+            strings
+            fallibleStrings
+            partialStrings
+        }
+    "#;
+    let root = Query {
+        items: vec![
+            Item {
+                value: "foo".to_string(),
+            },
+            Item {
+                value: "bar".to_string(),
+            },
+        ],
+    };
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(root));
+    let res = schema.execute(req).await;
+    assert_eq!(
+        res.data.clone().into_json().unwrap(),
+        serde_json::json!({
+            "numbers": [1, 2, 3],
+            "items": [{ "value": "foo" }, { "value": "bar" }],
+            "maybeNumbers": [1, null],
+            "strings": ["a", "b"],
+            "fallibleStrings": null,
+            "partialStrings": ["a", null],
+        })
+    );
+    let paths = res
+        .errors
+        .iter()
+        .map(|err| serde_json::to_value(&err.path).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        paths,
+        vec![
+            serde_json::json!(["fallibleStrings", 1]),
+            serde_json::json!(["partialStrings", 1]),
+        ]
+    );
+}
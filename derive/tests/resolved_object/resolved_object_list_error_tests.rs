@@ -0,0 +1,277 @@
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(thiserror::Error, Debug)]
+enum MyError {
+    #[error("Not found")]
+    NotFound,
+}
+
+fn items() -> Vec<Result<String, MyError>> {
+    vec![
+        Ok("a".to_string()),
+        Err(MyError::NotFound),
+        Ok("c".to_string()),
+    ]
+}
+
+macro_rules! error_paths {
+    ($res:expr) => {
+        $res.errors
+            .iter()
+            .map(|err| serde_json::to_value(&err.path).unwrap())
+            .collect::<Vec<_>>()
+    };
+}
+
+#[tokio::test]
+async fn test_nullable_items() {
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn strings(&self) -> Vec<Option<Result<String, MyError>>> {
+            items().into_iter().map(Some).collect()
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            strings
+        }
+    "#;
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(
+        res.data.clone().into_json().unwrap(),
+        serde_json::json!({ "strings": ["a", null, "c"] })
+    );
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(res.errors[0].message, "Not found");
+    assert_eq!(error_paths!(res), vec![serde_json::json!(["strings", 1])]);
+}
+
+#[tokio::test]
+async fn test_non_null_items_propagate_to_nullable_list() {
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn strings(&self) -> Option<Vec<Result<String, MyError>>> {
+            Some(items())
+        }
+        fn other(&self) -> String {
+            "other".to_string()
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            strings
+            other
+        }
+    "#;
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(
+        res.data.clone().into_json().unwrap(),
+        serde_json::json!({ "strings": null, "other": "other" })
+    );
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(res.errors[0].message, "Not found");
+    assert_eq!(error_paths!(res), vec![serde_json::json!(["strings", 1])]);
+}
+
+#[tokio::test]
+async fn test_nested_list_path() {
+    #[derive(ResolvedObject)]
+    struct Group {
+        fail: bool,
+    }
+
+    #[ResolvedObjectFields]
+    impl Group {
+        fn values(&self) -> Option<Vec<Result<String, MyError>>> {
+            if self.fail {
+                Some(items())
+            } else {
+                Some(vec![Ok("a".to_string())])
+            }
+        }
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn groups(&self) -> Vec<Group> {
+            vec![Group { fail: false }, Group { fail: true }]
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            groups {
+                values
+            }
+        }
+    "#;
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(
+        res.data.clone().into_json().unwrap(),
+        serde_json::json!({ "groups": [{ "values": ["a"] }, { "values": null }] })
+    );
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(
+        error_paths!(res),
+        vec![serde_json::json!(["groups", 1, "values", 1])]
+    );
+}
+
+#[tokio::test]
+async fn test_non_null_items_in_non_null_list() {
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn strings(&self) -> Vec<Result<String, MyError>> {
+            items()
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            strings
+        }
+    "#;
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(
+        res.data.clone().into_json().unwrap(),
+        serde_json::json!(null)
+    );
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(res.errors[0].message, "Not found");
+    assert_eq!(error_paths!(res), vec![serde_json::json!(["strings", 1])]);
+}
+
+#[tokio::test]
+async fn test_partial_list() {
+    #[derive(SimpleObject)]
+    struct Item {
+        #[graphql(partial_list)]
+        values: Vec<String>,
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        #[graphql(partial_list)]
+        fn strings(&self) -> Vec<Result<String, MyError>> {
+            items()
+        }
+        #[graphql(partial_list)]
+        async fn maybe_strings(&self) -> Result<Option<Vec<Result<String, MyError>>>, MyError> {
+            Ok(Some(items()))
+        }
+        fn item(&self) -> Item {
+            Item {
+                values: vec!["a".to_string(), "b".to_string()],
+            }
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Item {
+      values: [String]!
+    }
+
+    type Query {
+      strings: [String]!
+      maybeStrings: [String]
+      item: Item!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            strings
+            maybeStrings
+            item {
+                values
+            }
+        }
+    "#;
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(
+        res.data.clone().into_json().unwrap(),
+        serde_json::json!({
+            "strings": ["a", null, "c"],
+            "maybeStrings": ["a", null, "c"],
+            "item": { "values": ["a", "b"] },
+        })
+    );
+    assert_eq!(res.errors.len(), 2);
+    assert_eq!(
+        error_paths!(res),
+        vec![
+            serde_json::json!(["strings", 1]),
+            serde_json::json!(["maybeStrings", 1]),
+        ]
+    );
+}
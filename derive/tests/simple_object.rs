@@ -1,7 +1,11 @@
 mod schema_utils;
 mod simple_object {
+    mod complex_tests;
+    mod computed_tests;
+    mod flatten_tests;
     mod list_tests;
     mod object_tests;
+    mod paired_tests;
     mod type_tests;
     mod with_generics_tests;
 }
@@ -0,0 +1,110 @@
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+/// A user
+#[derive(SimpleObject)]
+#[graphql(complex)]
+struct User {
+    first_name: String,
+    last_name: String,
+    #[graphql(skip)]
+    age: i32,
+}
+
+#[ResolvedObjectFields]
+impl User {
+    fn full_name(&self) -> String {
+        format!("{} {}", self.first_name, self.last_name)
+    }
+    fn is_older_than(&self, age: i32) -> bool {
+        self.age > age
+    }
+}
+
+#[derive(SimpleObject)]
+#[graphql(root, complex)]
+struct Query {
+    user: User,
+}
+
+#[ResolvedObjectFields]
+impl Query {
+    async fn greeting(&self) -> String {
+        format!("hello {}", self.user.first_name)
+    }
+}
+
+#[derive(App)]
+struct App(Query);
+
+#[tokio::test]
+async fn test_complex() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Query {
+      greeting: String!
+      user: User!
+    }
+
+    "A user"
+    type User {
+      fullName: String!
+      isOlderThan(age: Int!): Boolean!
+      firstName: String!
+      lastName: String!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            greeting
+            user {
+                firstName
+                lastName
+                fullName
+                isOlderThan(age: 18)
+            }
+        }
+    "#;
+
+    let root = Query {
+        user: User {
+            first_name: "John".to_string(),
+            last_name: "Doe".to_string(),
+            age: 20,
+        },
+    };
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(root));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "greeting": "hello John",
+            "user": {
+                "firstName": "John",
+                "lastName": "Doe",
+                "fullName": "John Doe",
+                "isOlderThan": true,
+            },
+        })
+    );
+}
@@ -0,0 +1,181 @@
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+struct Money {
+    cents: i64,
+}
+
+fn format_money(money: &Money) -> String {
+    format!("${}.{:02}", money.cents / 100, money.cents % 100)
+}
+
+fn format_discount(discount: &Option<Money>) -> Option<String> {
+    discount.as_ref().map(format_money)
+}
+
+fn full_name(user: &User) -> String {
+    format!("{} {}", user.first_name, user.last_name)
+}
+
+fn initials(user: &User) -> Vec<String> {
+    [&user.first_name, &user.last_name]
+        .iter()
+        .filter_map(|name| name.chars().next())
+        .map(String::from)
+        .collect()
+}
+
+#[derive(SimpleObject)]
+#[graphql(computed(name = "fullName", ty = "String", with = "full_name"))]
+#[graphql(computed(name = "initials", ty = "Vec<String>", with = "initials"))]
+struct User {
+    first_name: String,
+    last_name: String,
+}
+
+#[derive(SimpleObject)]
+struct Product {
+    name: String,
+    /// The price of the product
+    #[graphql(with = "format_money")]
+    price: Money,
+    #[graphql(with = "format_discount", name = "sale")]
+    discount: Option<Money>,
+    #[graphql(flatten)]
+    seller: User,
+}
+
+#[derive(SimpleObject)]
+#[graphql(root)]
+struct Query {
+    product: Product,
+}
+
+#[derive(App)]
+struct App(Query);
+
+#[tokio::test]
+async fn test_computed() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Product {
+      name: String!
+      "The price of the product"
+      price: String!
+      sale: String
+      firstName: String!
+      lastName: String!
+      fullName: String!
+      initials: [String!]!
+    }
+
+    type Query {
+      product: Product!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            product {
+                name
+                price
+                sale
+                firstName
+                fullName
+                initials
+            }
+        }
+    "#;
+
+    let root = Query {
+        product: Product {
+            name: "foo".to_string(),
+            price: Money { cents: 1250 },
+            discount: None,
+            seller: User {
+                first_name: "John".to_string(),
+                last_name: "Doe".to_string(),
+            },
+        },
+    };
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(root));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "product": {
+                "name": "foo",
+                "price": "$12.50",
+                "sale": null,
+                "firstName": "John",
+                "fullName": "John Doe",
+                "initials": ["J", "D"],
+            },
+        })
+    );
+}
+
+#[test]
+fn test_computed_name_is_verbatim() {
+    fn account_name(account: &Account) -> String {
+        account.full_name.clone()
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(rename_fields = "snake_case")]
+    #[graphql(computed(name = "displayName", ty = "String", with = "account_name"))]
+    struct Account {
+        full_name: String,
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    struct Query {
+        account: Account,
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Account {
+      full_name: String!
+      displayName: String!
+    }
+
+    type Query {
+      account: Account!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+}
@@ -0,0 +1,142 @@
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(SimpleObject)]
+struct Audit {
+    /// Who created the entity
+    created_by: String,
+    #[graphql(deprecation = "use createdBy")]
+    author: String,
+}
+
+#[derive(SimpleObject)]
+struct Timestamps {
+    created_at: i32,
+    #[graphql(skip)]
+    #[allow(dead_code)]
+    internal: i32,
+    #[graphql(flatten)]
+    audit: Audit,
+}
+
+#[derive(SimpleObject)]
+struct Post {
+    title: String,
+    #[graphql(flatten)]
+    timestamps: Timestamps,
+}
+
+#[derive(SimpleObject)]
+#[graphql(root)]
+struct Query {
+    post: Post,
+    timestamps: Timestamps,
+}
+
+#[derive(App)]
+struct App(Query);
+
+fn create_query() -> Query {
+    Query {
+        post: Post {
+            title: "foo".to_string(),
+            timestamps: Timestamps {
+                created_at: 1,
+                internal: 0,
+                audit: Audit {
+                    created_by: "alice".to_string(),
+                    author: "bob".to_string(),
+                },
+            },
+        },
+        timestamps: Timestamps {
+            created_at: 2,
+            internal: 0,
+            audit: Audit {
+                created_by: "carol".to_string(),
+                author: "dave".to_string(),
+            },
+        },
+    }
+}
+
+#[tokio::test]
+async fn test_flatten() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Post {
+      title: String!
+      createdAt: Int!
+      "Who created the entity"
+      createdBy: String!
+      author: String! @deprecated(reason: "use createdBy")
+    }
+
+    type Query {
+      post: Post!
+      timestamps: Timestamps!
+    }
+
+    type Timestamps {
+      createdAt: Int!
+      "Who created the entity"
+      createdBy: String!
+      author: String! @deprecated(reason: "use createdBy")
+    }
+
+    "Marks an element of a GraphQL schema as no longer supported."
+    directive @deprecated(reason: String = "No longer supported") on FIELD_DEFINITION | ARGUMENT_DEFINITION | INPUT_FIELD_DEFINITION | ENUM_VALUE
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            post {
+                title
+                createdAt
+                createdBy
+                author
+            }
+            timestamps {
+                createdAt
+                createdBy
+            }
+        }
+    "#;
+
+    let req =
+        dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(create_query()));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "post": {
+                "title": "foo",
+                "createdAt": 1,
+                "createdBy": "alice",
+                "author": "bob",
+            },
+            "timestamps": {
+                "createdAt": 2,
+                "createdBy": "carol",
+            },
+        })
+    );
+}
@@ -0,0 +1,198 @@
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::InputObject;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+/// A postal address
+#[derive(SimpleObject, InputObject, Clone)]
+#[graphql(input_name = "AddressInput", rename_fields = "snake_case")]
+struct Address {
+    /// The street and house number
+    street_line: String,
+    city: String,
+    #[graphql(input(skip))]
+    verified: bool,
+    #[graphql(output(skip))]
+    #[allow(dead_code)]
+    note: Option<String>,
+    #[graphql(output(name = "postal_code"), input(name = "zip"))]
+    zip_code: String,
+    #[graphql(skip)]
+    #[allow(dead_code)]
+    internal: i32,
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn verify(&self, address: Address) -> Address {
+        Address {
+            verified: true,
+            ..address
+        }
+    }
+}
+
+#[derive(App)]
+struct App(Query);
+
+#[tokio::test]
+async fn test_paired() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    "A postal address"
+    type Address {
+      "The street and house number"
+      street_line: String!
+      city: String!
+      verified: Boolean!
+      postal_code: String!
+    }
+
+    "A postal address"
+    input AddressInput {
+      "The street and house number" street_line: String!
+      city: String!
+      note: String
+      zip: String!
+    }
+
+    type Query {
+      verify(address: AddressInput!): Address!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            verify(address: { street_line: "1 Main St", city: "Springfield", note: "back door", zip: "12345" }) {
+                street_line
+                city
+                verified
+                postal_code
+            }
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "verify": {
+                "street_line": "1 Main St",
+                "city": "Springfield",
+                "verified": true,
+                "postal_code": "12345",
+            },
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_paired_default_and_deprecation() {
+    #[derive(SimpleObject, InputObject, Clone)]
+    #[graphql(input_name = "SettingsInput", collect_errors)]
+    struct Settings {
+        #[graphql(default)]
+        page_size: i32,
+        #[graphql(default = "light")]
+        theme: String,
+        #[graphql(deprecation = "use theme")]
+        dark: Option<bool>,
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn save(&self, settings: Settings) -> Settings {
+            settings
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Query {
+      save(settings: SettingsInput!): Settings!
+    }
+
+    type Settings {
+      pageSize: Int!
+      theme: String!
+      dark: Boolean @deprecated(reason: "use theme")
+    }
+
+    input SettingsInput {
+      pageSize: Int! = 0
+      theme: String! = "light"
+      dark: Boolean @deprecated(reason: "use theme")
+    }
+
+    "Marks an element of a GraphQL schema as no longer supported."
+    directive @deprecated(reason: String = "No longer supported") on FIELD_DEFINITION | ARGUMENT_DEFINITION | INPUT_FIELD_DEFINITION | ENUM_VALUE
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            save(settings: {}) {
+                pageSize
+                theme
+                dark
+            }
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "save": {
+                "pageSize": 0,
+                "theme": "light",
+                "dark": null,
+            },
+        })
+    );
+}
@@ -0,0 +1,340 @@
+use dynamic_graphql::App;
+use dynamic_graphql::Enum;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::ID;
+use dynamic_graphql::InputObject;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::Transparent;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use dynamic_graphql::internal::InputTypeName;
+use dynamic_graphql::internal::OutputTypeName;
+use dynamic_graphql::internal::TypeName;
+
+use crate::schema_utils::normalize_schema;
+
+#[test]
+fn test_type_name() {
+    #[derive(Transparent)]
+    struct UserId(ID);
+
+    #[derive(Transparent)]
+    struct Email(String);
+
+    assert_eq!(<UserId as TypeName>::get_type_name(), "ID");
+    assert_eq!(<UserId as InputTypeName>::get_input_type_name(), "ID");
+    assert_eq!(<UserId as OutputTypeName>::get_output_type_name(), "ID");
+    assert_eq!(<Email as TypeName>::get_type_name(), "String");
+}
+
+#[test]
+fn test_schema() {
+    #[derive(Transparent)]
+    struct UserId(ID);
+
+    #[derive(Transparent)]
+    struct Email(String);
+
+    #[derive(SimpleObject)]
+    struct User {
+        id: UserId,
+        email: Option<Email>,
+        aliases: Vec<Email>,
+    }
+
+    #[derive(InputObject)]
+    struct UserInput {
+        email: Email,
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn user(id: UserId) -> Option<User> {
+            let _ = id;
+            None
+        }
+        fn create_user(input: UserInput) -> Email {
+            input.email
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Query {
+      user(id: ID!): User
+      createUser(input: UserInput!): String!
+    }
+
+    type User {
+      id: ID!
+      email: String
+      aliases: [String!]!
+    }
+
+    input UserInput {
+      email: String!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn test_query() {
+    #[derive(Transparent)]
+    struct UserId(ID);
+
+    #[derive(Transparent)]
+    struct Email(String);
+
+    #[derive(SimpleObject)]
+    struct User {
+        id: UserId,
+        email: Email,
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn user(id: UserId, email: Email) -> User {
+            User { id, email }
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            user(id: "1", email: "foo@example.com") {
+                id
+                email
+            }
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+    assert_eq!(
+        data,
+        serde_json::json!({ "user": { "id": "1", "email": "foo@example.com" } })
+    );
+}
+
+#[tokio::test]
+async fn test_enum() {
+    #[derive(Enum, Clone, Copy)]
+    enum Role {
+        Admin,
+        User,
+    }
+
+    #[derive(Transparent)]
+    struct UserRole(Role);
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn role(role: UserRole) -> UserRole {
+            role
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Query {
+      role(role: Role!): Role!
+    }
+
+    enum Role {
+      ADMIN
+      USER
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            role(role: ADMIN)
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+    assert_eq!(data, serde_json::json!({ "role": "ADMIN" }));
+}
+
+#[tokio::test]
+async fn test_validator() {
+    fn validate_email(value: &str) -> Result<(), &'static str> {
+        if value.contains('@') {
+            Ok(())
+        } else {
+            Err("invalid email")
+        }
+    }
+
+    #[derive(Transparent)]
+    #[graphql(validator(validate_email))]
+    struct Email(String);
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn email(email: Email) -> Email {
+            email
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            email(email: "foo@example.com")
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+    assert_eq!(data, serde_json::json!({ "email": "foo@example.com" }));
+
+    let query = r#"
+        query {
+            email(email: "foo")
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(
+        res.errors[0].message,
+        r#"Invalid value for argument "email": Failed to parse "String": invalid email"#,
+    );
+}
+
+#[tokio::test]
+async fn test_one_direction() {
+    #[derive(SimpleObject)]
+    struct User {
+        name: String,
+    }
+
+    #[derive(InputObject)]
+    struct UserFilter {
+        name: String,
+    }
+
+    #[derive(Transparent)]
+    #[graphql(output)]
+    struct CurrentUser(User);
+
+    #[derive(Transparent)]
+    #[graphql(input)]
+    struct Filter(UserFilter);
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn user(filter: Filter) -> CurrentUser {
+            CurrentUser(User {
+                name: filter.0.name,
+            })
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Query {
+      user(filter: UserFilter!): User!
+    }
+
+    type User {
+      name: String!
+    }
+
+    input UserFilter {
+      name: String!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            user(filter: { name: "foo" }) {
+                name
+            }
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+    assert_eq!(data, serde_json::json!({ "user": { "name": "foo" } }));
+}
@@ -1,6 +1,10 @@
 mod schema_utils;
 mod union {
+    mod extends_union_tests;
+    mod nested_tests;
+    mod struct_variant_tests;
     mod union_tests;
     mod with_generic_tests;
     mod with_interface;
+    mod with_interface_instance;
 }
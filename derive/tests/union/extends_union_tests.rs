@@ -0,0 +1,201 @@
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::Union;
+use dynamic_graphql::UnionValue;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use dynamic_graphql::internal::Registry;
+
+use crate::schema_utils::normalize_schema;
+
+mod core {
+    use super::*;
+
+    #[derive(SimpleObject)]
+    pub struct User {
+        pub name: String,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Union)]
+    pub enum SearchResult {
+        User(User),
+        Other(UnionValue<'static, SearchResult>),
+    }
+}
+
+mod plugin {
+    use super::core::SearchResult;
+    use super::*;
+
+    #[derive(SimpleObject)]
+    #[graphql(extends_union(SearchResult))]
+    pub struct Product {
+        pub title: String,
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(extends_union(SearchResult))]
+    pub struct Order;
+
+    #[ResolvedObjectFields]
+    impl Order {
+        fn total(&self) -> i32 {
+            42
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_extends_union() {
+    use self::core::SearchResult;
+    use self::core::User;
+    use self::plugin::Order;
+    use self::plugin::Product;
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn search(&self) -> Vec<SearchResult> {
+            vec![
+                SearchResult::User(User {
+                    name: "user".to_string(),
+                }),
+                SearchResult::Other(UnionValue::new_owned(Product {
+                    title: "product".to_string(),
+                })),
+            ]
+        }
+        fn others(&self) -> Vec<UnionValue<'static, SearchResult>> {
+            vec![UnionValue::new_owned(Order)]
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query, Product, Order);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Order {
+      total: Int!
+    }
+
+    type Product {
+      title: String!
+    }
+
+    type Query {
+      search: [SearchResult!]!
+      others: [SearchResult!]!
+    }
+
+    union SearchResult = User | Product | Order
+
+    type User {
+      name: String!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            search {
+                __typename
+                ... on User {
+                    name
+                }
+                ... on Product {
+                    title
+                }
+            }
+            others {
+                __typename
+                ... on Order {
+                    total
+                }
+            }
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "search": [
+                { "__typename": "User", "name": "user" },
+                { "__typename": "Product", "title": "product" },
+            ],
+            "others": [
+                { "__typename": "Order", "total": 42 },
+            ],
+        })
+    );
+}
+
+#[test]
+fn test_update_union() {
+    use self::core::SearchResult;
+
+    #[derive(SimpleObject)]
+    struct Review {
+        stars: i32,
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    struct Query {
+        search: Vec<SearchResult>,
+    }
+
+    let registry = Registry::new()
+        .register::<Query>()
+        .register::<Review>()
+        .update_union("SearchResult", "Review");
+    let schema = registry.create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Query {
+      search: [SearchResult!]!
+    }
+
+    type Review {
+      stars: Int!
+    }
+
+    union SearchResult = User | Review
+
+    type User {
+      name: String!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+}
@@ -0,0 +1,132 @@
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::Union;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use dynamic_graphql::internal::UnionMember as _;
+
+use crate::schema_utils::normalize_schema;
+
+#[tokio::test]
+async fn test_nested_union() {
+    #[derive(SimpleObject)]
+    struct Dog {
+        name: String,
+    }
+
+    #[derive(SimpleObject)]
+    struct Cat {
+        life: i32,
+    }
+
+    #[derive(SimpleObject)]
+    struct Snake {
+        length: i32,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Union)]
+    enum Pet {
+        Dog(Dog),
+        Cat(Cat),
+    }
+
+    #[allow(dead_code)]
+    #[derive(Union)]
+    enum Animal {
+        Pet(Pet),
+        Snake(Snake),
+        // duplicated members are added once
+        Cat(Cat),
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    struct Query {
+        animals: Vec<Animal>,
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    assert_eq!(
+        Animal::get_union_member_types(),
+        vec!["Dog", "Cat", "Snake", "Cat"]
+    );
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    union Animal = Dog | Cat | Snake
+
+    type Cat {
+      life: Int!
+    }
+
+    type Dog {
+      name: String!
+    }
+
+    union Pet = Dog | Cat
+
+    type Query {
+      animals: [Animal!]!
+    }
+
+    type Snake {
+      length: Int!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            animals {
+                __typename
+                ... on Dog {
+                    name
+                }
+                ... on Cat {
+                    life
+                }
+                ... on Snake {
+                    length
+                }
+            }
+        }
+    "#;
+
+    let root = Query {
+        animals: vec![
+            Animal::Pet(Pet::Dog(Dog {
+                name: "dog".to_string(),
+            })),
+            Animal::Pet(Pet::Cat(Cat { life: 9 })),
+            Animal::Snake(Snake { length: 3 }),
+        ],
+    };
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(root));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "animals": [
+                { "__typename": "Dog", "name": "dog" },
+                { "__typename": "Cat", "life": 9 },
+                { "__typename": "Snake", "length": 3 },
+            ]
+        })
+    );
+}
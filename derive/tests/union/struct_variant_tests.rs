@@ -0,0 +1,210 @@
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::Union;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(SimpleObject)]
+struct Polygon {
+    sides: i32,
+}
+
+/// A shape
+#[derive(Union)]
+enum Shape {
+    /// A circle
+    Circle {
+        radius: f64,
+        #[graphql(skip)]
+        #[allow(dead_code)]
+        center: (f64, f64),
+    },
+    #[graphql(type_name = "Rect")]
+    Rectangle {
+        width: f64,
+        #[graphql(name = "tall", deprecation = "use width")]
+        height: f64,
+    },
+    Point,
+    Polygon(Polygon),
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn shapes(&self) -> Vec<Shape> {
+        vec![
+            Shape::Circle {
+                radius: 1.5,
+                center: (0.0, 0.0),
+            },
+            Shape::Rectangle {
+                width: 2.0,
+                height: 3.0,
+            },
+            Shape::Point,
+            Shape::Polygon(Polygon { sides: 5 }),
+        ]
+    }
+}
+
+#[derive(App)]
+struct App(Query);
+
+#[tokio::test]
+async fn test_struct_variants() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Polygon {
+      sides: Int!
+    }
+
+    type Query {
+      shapes: [Shape!]!
+    }
+
+    type Rect {
+      width: Float!
+      tall: Float! @deprecated(reason: "use width")
+    }
+
+    "A shape"
+    union Shape = ShapeCircle | Rect | ShapePoint | Polygon
+
+    "A circle"
+    type ShapeCircle {
+      radius: Float!
+    }
+
+    type ShapePoint {
+      _: Boolean!
+    }
+
+    "Marks an element of a GraphQL schema as no longer supported."
+    directive @deprecated(reason: String = "No longer supported") on FIELD_DEFINITION | ARGUMENT_DEFINITION | INPUT_FIELD_DEFINITION | ENUM_VALUE
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            shapes {
+                __typename
+                ... on ShapeCircle {
+                    radius
+                }
+                ... on Rect {
+                    width
+                    tall
+                }
+                ... on Polygon {
+                    sides
+                }
+            }
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "shapes": [
+                { "__typename": "ShapeCircle", "radius": 1.5 },
+                { "__typename": "Rect", "width": 2.0, "tall": 3.0 },
+                { "__typename": "ShapePoint" },
+                { "__typename": "Polygon", "sides": 5 },
+            ],
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_rename_fields() {
+    #[derive(Union)]
+    #[graphql(rename_fields = "snake_case")]
+    enum Event {
+        Moved { from_x: i32, to_x: i32 },
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn event(&self) -> Event {
+            Event::Moved { from_x: 1, to_x: 2 }
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    union Event = EventMoved
+
+    type EventMoved {
+      from_x: Int!
+      to_x: Int!
+    }
+
+    type Query {
+      event: Event!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            event {
+                ... on EventMoved {
+                    from_x
+                    to_x
+                }
+            }
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "event": { "from_x": 1, "to_x": 2 },
+        })
+    );
+}
@@ -0,0 +1,137 @@
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::Instance;
+use dynamic_graphql::Interface;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::Union;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+#[tokio::test]
+async fn test_interface_instance_member() {
+    #[Interface]
+    trait Named {
+        fn name(&self) -> String;
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(mark(Named))]
+    struct Cat {
+        name: String,
+        life: i32,
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(mark(Named))]
+    struct Dog {
+        name: String,
+        power: i32,
+    }
+
+    #[derive(SimpleObject)]
+    struct Snake {
+        length: i32,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Union)]
+    enum Animal {
+        Named(Instance<'static, dyn Named>),
+        Snake(Snake),
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn animals(&self) -> Vec<Animal> {
+            vec![
+                Animal::Named(Instance::new_owned(Dog {
+                    name: "dog".to_string(),
+                    power: 100,
+                })),
+                Animal::Named(Instance::new_owned(Cat {
+                    name: "cat".to_string(),
+                    life: 9,
+                })),
+                Animal::Snake(Snake { length: 3 }),
+            ]
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query, Dog, Cat);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    union Animal = Cat | Dog | Snake
+
+    type Cat implements Named {
+      name: String!
+      life: Int!
+    }
+
+    type Dog implements Named {
+      name: String!
+      power: Int!
+    }
+
+    interface Named {
+      name: String!
+    }
+
+    type Query {
+      animals: [Animal!]!
+    }
+
+    type Snake {
+      length: Int!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            animals {
+                __typename
+                ... on Named {
+                    name
+                }
+                ... on Snake {
+                    length
+                }
+            }
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "animals": [
+                { "__typename": "Dog", "name": "dog" },
+                { "__typename": "Cat", "name": "cat" },
+                { "__typename": "Snake", "length": 3 },
+            ]
+        })
+    );
+}
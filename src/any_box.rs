@@ -4,6 +4,7 @@ use async_graphql::Context;
 use async_graphql::dynamic::FieldValue;
 
 use crate::resolve::ResolveOwned;
+use crate::resolve::ResolveRef;
 
 pub enum AnyBox<'a> {
     Owned(Box<dyn Any + Send + Sync>, String),
@@ -17,6 +18,34 @@ impl<'a> AnyBox<'a> {
     pub fn new_borrowed<T: Any + Send + Sync + Sized>(value: &'a T, ty: String) -> Self {
         Self::Borrowed(value, ty)
     }
+    pub fn new_boxed(value: Box<dyn Any + Send + Sync>, ty: String) -> Self {
+        Self::Owned(value, ty)
+    }
+    /// The GraphQL name of the object type of the value
+    pub fn type_name(&self) -> &str {
+        match self {
+            AnyBox::Owned(_, name) | AnyBox::Borrowed(_, name) => name,
+        }
+    }
+    pub fn is<T: Any>(&self) -> bool {
+        self.downcast_ref::<T>().is_some()
+    }
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        match self {
+            AnyBox::Owned(obj, _) => obj.downcast_ref(),
+            AnyBox::Borrowed(obj, _) => obj.downcast_ref(),
+        }
+    }
+    /// Take the value out of the box, borrowed values are returned as `Err`
+    pub fn downcast<T: Any>(self) -> Result<T, Self> {
+        match self {
+            AnyBox::Owned(obj, name) => match obj.downcast() {
+                Ok(value) => Ok(*value),
+                Err(obj) => Err(AnyBox::Owned(obj, name)),
+            },
+            borrowed @ AnyBox::Borrowed(..) => Err(borrowed),
+        }
+    }
 }
 
 impl<'a> ResolveOwned<'a> for AnyBox<'a> {
@@ -27,3 +56,16 @@ impl<'a> ResolveOwned<'a> for AnyBox<'a> {
         }
     }
 }
+
+impl<'a> ResolveRef<'a> for AnyBox<'_> {
+    fn resolve_ref(&'a self, _ctx: &Context) -> async_graphql::Result<Option<FieldValue<'a>>> {
+        match self {
+            AnyBox::Owned(obj, name) => Ok(Some(
+                FieldValue::borrowed_any(obj.as_ref()).with_type(name.clone()),
+            )),
+            AnyBox::Borrowed(obj, name) => {
+                Ok(Some(FieldValue::borrowed_any(*obj).with_type(name.clone())))
+            }
+        }
+    }
+}
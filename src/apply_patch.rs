@@ -0,0 +1,41 @@
+use crate::MaybeUndefined;
+
+/// Applies a field of a `#[graphql(patch_for = "...")]` input object to the field of the target
+///
+/// `MaybeUndefined<T>` patches `Option<T>`, `null` clears the value and an absent field keeps it.
+/// `Option<T>` patches `T` and `Option<T>`, `null` and an absent field keep the value, which opts a
+/// field of a nullable target out of clearing.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't patch a field of type `{T}`",
+    label = "the field of the patch input object doesn't match the field of the target",
+    note = "use `MaybeUndefined<T>` or `Option<T>` to patch `Option<T>`, and `Option<T>` to patch `T`"
+)]
+pub trait ApplyPatch<T> {
+    fn apply_patch(self, target: &mut T);
+}
+
+impl<T> ApplyPatch<Option<T>> for MaybeUndefined<T> {
+    fn apply_patch(self, target: &mut Option<T>) {
+        match self {
+            MaybeUndefined::Undefined => {}
+            MaybeUndefined::Null => *target = None,
+            MaybeUndefined::Value(value) => *target = Some(value),
+        }
+    }
+}
+
+impl<T> ApplyPatch<T> for Option<T> {
+    fn apply_patch(self, target: &mut T) {
+        if let Some(value) = self {
+            *target = value;
+        }
+    }
+}
+
+impl<T> ApplyPatch<Option<T>> for Option<T> {
+    fn apply_patch(self, target: &mut Option<T>) {
+        if let Some(value) = self {
+            *target = Some(value);
+        }
+    }
+}
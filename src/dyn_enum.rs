@@ -0,0 +1,152 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::marker::PhantomData;
+
+use async_graphql::Context;
+use async_graphql::dynamic::FieldValue;
+
+use crate::Name;
+use crate::Value;
+use crate::dynamic;
+use crate::errors::InputValueResult;
+use crate::from_value::FromValue;
+use crate::registry::Registry;
+use crate::resolve::ResolveOwned;
+use crate::resolve::ResolveRef;
+use crate::to_input_value::ToInputValue;
+use crate::types::Enum;
+use crate::types::InputTypeName;
+use crate::types::OutputTypeName;
+use crate::types::Register;
+use crate::types::TypeName;
+
+/// Names the GraphQL enum of a [`DynEnum`]
+pub trait DynEnumTag: Send + Sync + 'static {
+    fn get_enum_name() -> Cow<'static, str>;
+    fn get_enum_description() -> Option<Cow<'static, str>> {
+        None
+    }
+}
+
+/// A value of the extendable enum named by `T`.
+///
+/// The values of the enum are added by the modules with `#[derive(EnumExtension)]` or
+/// `Registry::update_enum`. The validation of the request checks the values of the inputs,
+/// literals and variables, against the registered items before `FromValue` is called, which
+/// has no access to the schema, so only the added values are accepted.
+pub struct DynEnum<T: DynEnumTag> {
+    value: String,
+    _tag: PhantomData<T>,
+}
+
+impl<T: DynEnumTag> DynEnum<T> {
+    pub fn new(value: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            _tag: PhantomData,
+        }
+    }
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+    pub fn into_string(self) -> String {
+        self.value
+    }
+}
+
+impl<T: DynEnumTag> Clone for DynEnum<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<T: DynEnumTag> PartialEq for DynEnum<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: DynEnumTag> Eq for DynEnum<T> {}
+
+impl<T: DynEnumTag> std::hash::Hash for DynEnum<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl<T: DynEnumTag> fmt::Debug for DynEnum<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DynEnum").field(&self.value).finish()
+    }
+}
+
+impl<T: DynEnumTag> fmt::Display for DynEnum<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.value)
+    }
+}
+
+impl<T: DynEnumTag> PartialEq<str> for DynEnum<T> {
+    fn eq(&self, other: &str) -> bool {
+        self.value == other
+    }
+}
+
+impl<T: DynEnumTag> PartialEq<&str> for DynEnum<T> {
+    fn eq(&self, other: &&str) -> bool {
+        self.value == *other
+    }
+}
+
+impl<T: DynEnumTag> From<&DynEnum<T>> for Value {
+    fn from(value: &DynEnum<T>) -> Self {
+        Value::Enum(Name::new(&value.value))
+    }
+}
+
+impl<T: DynEnumTag> Register for DynEnum<T> {
+    fn register(registry: Registry) -> Registry {
+        let object = dynamic::Enum::new(T::get_enum_name());
+        let object = match T::get_enum_description() {
+            Some(description) => object.description(description),
+            None => object,
+        };
+        registry.register_type(object)
+    }
+}
+
+impl<T: DynEnumTag> TypeName for DynEnum<T> {
+    fn get_type_name() -> Cow<'static, str> {
+        T::get_enum_name()
+    }
+}
+
+impl<T: DynEnumTag> InputTypeName for DynEnum<T> {}
+
+impl<T: DynEnumTag> OutputTypeName for DynEnum<T> {}
+
+impl<T: DynEnumTag> Enum for DynEnum<T> {}
+
+impl<T: DynEnumTag> FromValue for DynEnum<T> {
+    fn from_value(value: crate::Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
+        Ok(Self::new(value?.enum_name()?))
+    }
+}
+
+impl<T: DynEnumTag> ToInputValue for DynEnum<T> {
+    fn to_input_value(&self) -> Value {
+        self.into()
+    }
+}
+
+impl<'a, T: DynEnumTag> ResolveOwned<'a> for DynEnum<T> {
+    fn resolve_owned(self, _ctx: &Context) -> crate::Result<Option<FieldValue<'a>>> {
+        Ok(Some(FieldValue::value(&self)))
+    }
+}
+
+impl<'a, T: DynEnumTag> ResolveRef<'a> for DynEnum<T> {
+    fn resolve_ref(&'a self, _ctx: &Context) -> crate::Result<Option<FieldValue<'a>>> {
+        Ok(Some(FieldValue::value(self)))
+    }
+}
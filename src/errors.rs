@@ -1,23 +1,53 @@
 use std::fmt::Display;
 use std::marker::PhantomData;
 
+use async_graphql::Error;
 use async_graphql::ErrorExtensionValues;
+use async_graphql::ErrorExtensions;
 use async_graphql::Pos;
 use async_graphql::ServerError;
 use async_graphql::dynamic::TypeRef;
 
 use crate::Value;
+use crate::data::SchemaData;
 use crate::types::GetInputTypeRef;
+use crate::types::GraphQLError;
 
 fn get_type_name<T: GetInputTypeRef>() -> String {
     let type_ref: TypeRef = <Option<T>>::get_input_type_ref().into();
     type_ref.to_string()
 }
 
+/// An error of a field or a list item of an input value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputFieldError {
+    /// The path of the field in the input value, e.g. `addresses[2].zip`
+    pub path: String,
+    pub message: String,
+}
+
+impl InputFieldError {
+    fn with_prefix(mut self, prefix: &str) -> Self {
+        self.path = join_path(prefix, &self.path);
+        self
+    }
+}
+
+fn join_path(prefix: &str, path: &str) -> String {
+    if path.is_empty() {
+        prefix.to_string()
+    } else if path.starts_with('[') {
+        format!("{}{}", prefix, path)
+    } else {
+        format!("{}.{}", prefix, path)
+    }
+}
+
 #[derive(Debug)]
 pub struct InputValueError<T> {
     message: String,
     extensions: Option<ErrorExtensionValues>,
+    field_errors: Vec<InputFieldError>,
     phantom: PhantomData<T>,
 }
 
@@ -26,6 +56,7 @@ impl<T> InputValueError<T> {
         Self {
             message,
             extensions: None,
+            field_errors: Vec::new(),
             phantom: PhantomData,
         }
     }
@@ -62,7 +93,7 @@ impl<T> InputValueError<T> {
     where
         T: GetInputTypeRef,
     {
-        if get_type_name::<T>() != get_type_name::<U>() {
+        let mut error = if get_type_name::<T>() != get_type_name::<U>() {
             InputValueError::new(format!(
                 r#"{} (occurred while parsing "{}")"#,
                 self.message,
@@ -70,8 +101,90 @@ impl<T> InputValueError<T> {
             ))
         } else {
             InputValueError::new(self.message)
+        };
+        error.field_errors = self.field_errors;
+        error
+    }
+
+    /// The errors of the fields and list items, with their path in the input value.
+    ///
+    /// Empty when the error is not caused by a field or a list item.
+    pub fn field_errors(&self) -> &[InputFieldError] {
+        &self.field_errors
+    }
+
+    /// Move the error to the field `name` of the parent input value.
+    pub fn in_field<U: GetInputTypeRef>(self, name: &str) -> InputValueError<U> {
+        let message = format!(
+            r#"Failed to parse "{}": Invalid value for field "{}": {}"#,
+            get_type_name::<U>(),
+            name,
+            self.message
+        );
+        self.nest(name, message)
+    }
+
+    /// Move the error to the item `index` of the parent list.
+    pub fn in_item<U>(self, index: usize) -> InputValueError<U> {
+        let message = self.message.clone();
+        self.nest(&format!("[{}]", index), message)
+    }
+
+    fn nest<U>(self, segment: &str, message: String) -> InputValueError<U> {
+        let field_errors = if self.field_errors.is_empty() {
+            vec![InputFieldError {
+                path: segment.to_string(),
+                message: self.message,
+            }]
+        } else {
+            self.field_errors
+                .into_iter()
+                .map(|error| error.with_prefix(segment))
+                .collect()
+        };
+        InputValueError {
+            message,
+            extensions: self.extensions,
+            field_errors,
+            phantom: PhantomData,
         }
     }
+
+    /// Combine the errors of the fields or the items of an input value.
+    ///
+    /// The message of the first error is kept with the number of the other field errors, and the
+    /// field errors of all of them are collected.
+    pub fn combine(errors: Vec<InputValueError<T>>) -> Option<Self> {
+        let mut errors = errors.into_iter();
+        let mut first = errors.next()?;
+        for error in errors {
+            first.field_errors.extend(error.field_errors);
+        }
+        match first.field_errors.len().saturating_sub(1) {
+            0 => {}
+            1 => first.message = format!("{} (and 1 more error)", first.message),
+            more => first.message = format!("{} (and {} more errors)", first.message, more),
+        }
+        Some(first)
+    }
+
+    fn into_extensions(self, prefix: Option<&str>) -> (String, Option<ErrorExtensionValues>) {
+        if self.field_errors.is_empty() {
+            return (self.message, self.extensions);
+        }
+        let errors = self
+            .field_errors
+            .into_iter()
+            .map(|error| match prefix {
+                Some(prefix) => error.with_prefix(prefix),
+                None => error,
+            })
+            .map(|error| async_graphql::value!({ "path": error.path, "message": error.message }))
+            .collect::<Vec<_>>();
+        let mut extensions = self.extensions.unwrap_or_default();
+        extensions.set("errors", Value::List(errors));
+        (self.message, Some(extensions))
+    }
     pub fn with_extension(mut self, name: impl AsRef<str>, value: impl Into<Value>) -> Self {
         self.extensions
             .get_or_insert_with(ErrorExtensionValues::default)
@@ -81,25 +194,26 @@ impl<T> InputValueError<T> {
 
     /// Convert the error into a server error.
     pub fn into_server_error(self, pos: Pos) -> ServerError {
-        let mut err = ServerError::new(self.message, Some(pos));
-        err.extensions = self.extensions;
+        let (message, extensions) = self.into_extensions(None);
+        let mut err = ServerError::new(message, Some(pos));
+        err.extensions = extensions;
         err
     }
 
     pub fn into_arg_error(self, name: &str) -> crate::Error {
+        let (message, extensions) = self.into_extensions(Some(name));
         let mut error = crate::Error::new(format!(
             "Invalid value for argument \"{}\": {}",
-            name, self.message
+            name, message
         ));
-        error.extensions = self.extensions;
+        error.extensions = extensions;
         error
     }
     pub fn into_field_error(self, name: &str) -> crate::Error {
-        let mut error = crate::Error::new(format!(
-            "Invalid value for field \"{}\": {}",
-            name, self.message
-        ));
-        error.extensions = self.extensions;
+        let (message, extensions) = self.into_extensions(Some(name));
+        let mut error =
+            crate::Error::new(format!("Invalid value for field \"{}\": {}", name, message));
+        error.extensions = extensions;
         error
     }
 }
@@ -114,12 +228,98 @@ impl<T: GetInputTypeRef> From<async_graphql::Error> for InputValueError<T> {
 
 impl<T> From<InputValueError<T>> for async_graphql::Error {
     fn from(value: InputValueError<T>) -> Self {
+        let (message, extensions) = value.into_extensions(None);
         Self {
-            message: value.message,
+            message,
             source: None,
-            extensions: value.extensions,
+            extensions,
         }
     }
 }
 
 pub type InputValueResult<T> = Result<T, InputValueError<T>>;
+
+/// An error code of an error type defined by `#[derive(GraphQLError)]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorCode {
+    /// The name of the error type
+    pub error: &'static str,
+    /// The value of the `code` extension
+    pub code: &'static str,
+    /// The doc comment of the variant
+    pub description: Option<&'static str>,
+}
+
+/// All the error codes registered in the schema.
+///
+/// Available from the [`Registry`][crate::internal::Registry] and the schema data.
+#[derive(Debug, Clone, Default)]
+pub struct ErrorCodes(Vec<ErrorCode>);
+
+impl ErrorCodes {
+    pub fn codes(&self) -> &[ErrorCode] {
+        &self.0
+    }
+
+    fn extend(&mut self, codes: &[ErrorCode]) {
+        for code in codes {
+            if !self.0.contains(code) {
+                self.0.push(*code);
+            }
+        }
+    }
+}
+
+pub(crate) fn register_error<E: GraphQLError>(data: &mut SchemaData) {
+    data.get_mut_or_default::<ErrorCodes>()
+        .extend(E::ERROR_CODES);
+}
+
+pub(crate) fn get_error_codes(data: &SchemaData) -> &[ErrorCode] {
+    data.get::<ErrorCodes>()
+        .map(ErrorCodes::codes)
+        .unwrap_or_default()
+}
+
+/// The conversion of the error of a resolver which is a [`GraphQLError`], with its extensions
+pub struct ExtensionsErrorKind;
+
+/// The conversion of the error of a resolver with [`Into<Error>`]
+pub struct IntoErrorKind;
+
+/// Selects [`ExtensionsErrorKind`] for the errors implementing [`GraphQLError`].
+///
+/// It is implemented for `E`, so `(&err).resolver_error_kind()` prefers it over
+/// [`IntoResolverErrorKind`], implemented for `&E`, the `Display` errors implement both.
+pub trait ExtensionsResolverErrorKind {
+    #[inline]
+    fn resolver_error_kind(&self) -> ExtensionsErrorKind {
+        ExtensionsErrorKind
+    }
+}
+
+impl<E: GraphQLError + ErrorExtensions> ExtensionsResolverErrorKind for E {}
+
+/// Selects [`IntoErrorKind`] for the other errors, see [`ExtensionsResolverErrorKind`]
+pub trait IntoResolverErrorKind {
+    #[inline]
+    fn resolver_error_kind(&self) -> IntoErrorKind {
+        IntoErrorKind
+    }
+}
+
+impl<E: Into<Error>> IntoResolverErrorKind for &E {}
+
+impl ExtensionsErrorKind {
+    #[inline]
+    pub fn into_resolver_error<E: ErrorExtensions>(self, err: E) -> Error {
+        err.extend()
+    }
+}
+
+impl IntoErrorKind {
+    #[inline]
+    pub fn into_resolver_error<E: Into<Error>>(self, err: E) -> Error {
+        err.into()
+    }
+}
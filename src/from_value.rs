@@ -124,10 +124,19 @@ where
     T: FromValue + GetInputTypeRef,
 {
     fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
-        value?
-            .list()?
-            .iter()
-            .map(|v| T::from_value(Ok(v)).map_err(InputValueError::propagate))
-            .collect()
+        let list = value?.list()?;
+        let mut items = Vec::with_capacity(list.len());
+        let mut errors = Vec::new();
+        // parse all the items to report the errors of all of them
+        for (index, item) in list.iter().enumerate() {
+            match T::from_value(Ok(item)) {
+                Ok(item) => items.push(item),
+                Err(err) => errors.push(err.propagate::<Self>().in_item(index)),
+            }
+        }
+        match InputValueError::combine(errors) {
+            Some(err) => Err(err),
+            None => Ok(items),
+        }
     }
 }
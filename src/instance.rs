@@ -1,4 +1,6 @@
+use std::any::Any;
 use std::borrow::Cow;
+use std::sync::Arc;
 
 use async_graphql::Context;
 use async_graphql::dynamic::FieldValue;
@@ -6,12 +8,16 @@ use async_graphql::dynamic::FieldValue;
 use crate::any_box::AnyBox;
 use crate::registry::Registry;
 use crate::resolve::ResolveOwned;
+use crate::resolve::ResolveRef;
+use crate::signature::FieldNames;
+use crate::signature::TypeSignature;
 use crate::types::Interface;
 use crate::types::InterfaceMark;
 use crate::types::Object;
 use crate::types::OutputTypeName;
 use crate::types::Register;
 use crate::types::TypeName;
+use crate::types::UnionMember;
 
 pub struct Instance<'v, I, T = ()>
 where
@@ -51,6 +57,102 @@ where
     }
 }
 
+impl<'v, I> Instance<'v, I>
+where
+    I: ?Sized + Interface,
+{
+    /// Convert the instance to an instance of an interface extended by `I`
+    #[inline]
+    pub fn upcast<P>(self) -> Instance<'v, P>
+    where
+        P: ?Sized + Interface,
+        I: InterfaceMark<P>,
+    {
+        Instance {
+            _interface: std::marker::PhantomData,
+            _target: std::marker::PhantomData,
+            value: self.value,
+        }
+    }
+
+    /// The GraphQL name of the object type of the instance
+    #[inline]
+    pub fn type_name(&self) -> &str {
+        self.value.type_name()
+    }
+
+    /// Returns `true` if the instance holds a `T`
+    #[inline]
+    pub fn is<T: Any>(&self) -> bool {
+        self.value.is::<T>()
+    }
+
+    #[inline]
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.value.downcast_ref::<T>()
+    }
+
+    /// Take the `T` out of the instance.
+    ///
+    /// Returns the instance back if it holds another type or if it was created with
+    /// [`Instance::new_borrowed`].
+    #[inline]
+    pub fn downcast<T: Any>(self) -> Result<T, Self> {
+        self.value.downcast::<T>().map_err(|value| Instance {
+            _interface: std::marker::PhantomData,
+            _target: std::marker::PhantomData,
+            value,
+        })
+    }
+}
+
+/// A value of an object type, that can be converted to an [`Instance`] without knowing its type.
+///
+/// Add it as a supertrait of an interface trait (`trait Node: AnyObject`) to convert
+/// `Box<dyn Node>` to `Instance<dyn Node>`. It is implemented for all the object types.
+pub trait AnyObject: Any + Send + Sync {
+    fn object_type_name(&self) -> Cow<'static, str>;
+    fn into_any(self: Box<Self>) -> Box<dyn Any + Send + Sync>;
+}
+
+impl<T> AnyObject for T
+where
+    T: Object + Send + Sync + 'static,
+{
+    fn object_type_name(&self) -> Cow<'static, str> {
+        <T as Object>::get_object_type_name()
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any + Send + Sync> {
+        self
+    }
+}
+
+impl<I> From<Box<I>> for Instance<'_, I>
+where
+    I: ?Sized + Interface + AnyObject,
+{
+    fn from(value: Box<I>) -> Self {
+        let name = value.object_type_name().to_string();
+        Instance {
+            _interface: std::marker::PhantomData,
+            _target: std::marker::PhantomData,
+            value: AnyBox::new_boxed(value.into_any(), name),
+        }
+    }
+}
+
+/// The value is cloned if the `Arc` is shared
+impl<I, T> From<Arc<T>> for Instance<'_, I>
+where
+    I: ?Sized + Interface,
+    T: InterfaceMark<I> + Object + Clone + Send + Sync + 'static,
+{
+    fn from(value: Arc<T>) -> Self {
+        Instance::new_owned(Arc::unwrap_or_clone(value))
+    }
+}
+
 impl<'a, I> ResolveOwned<'a> for Instance<'a, I>
 where
     I: ?Sized + Interface,
@@ -60,16 +162,35 @@ where
     }
 }
 
+impl<'a, I> ResolveRef<'a> for Instance<'_, I>
+where
+    I: ?Sized + Interface,
+{
+    fn resolve_ref(&'a self, ctx: &Context) -> async_graphql::Result<Option<FieldValue<'a>>> {
+        self.value.resolve_ref(ctx)
+    }
+}
+
 pub trait RegisterInstance<I, T>
 where
     I: ?Sized,
     T: Object + 'static,
     T: Send + Sync,
 {
+    /// The trait methods which can be named in `interface_default`, checked at compile time
+    const METHOD_NAMES: FieldNames = FieldNames::EMPTY;
+
     #[inline]
     fn register_instance(registry: Registry) -> Registry {
         registry
     }
+    /// Add the interface fields of the trait methods named in `fields` to the object `T`.
+    ///
+    /// Used by the objects marked with `#[graphql(mark(Trait), interface_default(field))]`
+    #[inline]
+    fn register_default_fields(registry: Registry, _fields: &'static [&'static str]) -> Registry {
+        registry
+    }
 }
 
 impl<I, T> Register for Instance<'_, I, T>
@@ -82,7 +203,9 @@ where
 {
     #[inline]
     fn register(registry: Registry) -> Registry {
-        let registry = registry.register::<I>();
+        let registry = registry.register::<I>().add_signature(
+            TypeSignature::new(T::get_object_type_name()).implement(I::get_interface_type_name()),
+        );
         <I as RegisterInstance<I, T>>::register_instance(registry)
     }
 }
@@ -108,3 +231,13 @@ where
 }
 
 impl<I> OutputTypeName for Instance<'_, I> where I: Interface + 'static + ?Sized {}
+
+impl<I> UnionMember for Instance<'_, I>
+where
+    I: Interface + 'static + ?Sized,
+{
+    /// the implementors are added to the union when the schema is created
+    fn get_union_member_types() -> Vec<Cow<'static, str>> {
+        vec![]
+    }
+}
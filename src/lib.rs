@@ -1,12 +1,20 @@
+// the derives refer to the crate by name, also in the doc tests of this crate
+extern crate self as dynamic_graphql;
+
 mod any_box;
+mod apply_patch;
 mod data;
+mod dyn_enum;
 mod errors;
 mod from_value;
 mod instance;
 mod registry;
 mod resolve;
+mod signature;
+mod to_input_value;
 mod type_ref_builder;
 mod types;
+mod union_value;
 mod upload;
 
 #[doc(no_inline)]
@@ -16,6 +24,10 @@ pub use async_graphql::Data;
 #[doc(no_inline)]
 pub use async_graphql::Error;
 #[doc(no_inline)]
+pub use async_graphql::ErrorExtensionValues;
+#[doc(no_inline)]
+pub use async_graphql::ErrorExtensions;
+#[doc(no_inline)]
 pub use async_graphql::ID;
 #[doc(no_inline)]
 pub use async_graphql::Lookahead;
@@ -44,19 +56,37 @@ pub use async_graphql::value;
 
 pub mod internal {
     pub use crate::any_box::AnyBox;
+    pub use crate::apply_patch::ApplyPatch;
+    pub use crate::errors::ExtensionsErrorKind;
+    pub use crate::errors::ExtensionsResolverErrorKind;
+    pub use crate::errors::InputFieldError;
     pub use crate::errors::InputValueError;
     pub use crate::errors::InputValueResult;
+    pub use crate::errors::IntoErrorKind;
+    pub use crate::errors::IntoResolverErrorKind;
     pub use crate::from_value::FromValue;
     pub use crate::instance::RegisterInstance;
     pub use crate::registry::Registry;
     pub use crate::resolve::Resolve;
+    pub use crate::resolve::ResolveIter;
     pub use crate::resolve::ResolveOwned;
+    pub use crate::resolve::ResolvePartialList;
     pub use crate::resolve::ResolveRef;
+    pub use crate::resolve::collect_stream;
+    pub use crate::signature::FieldNames;
+    pub use crate::signature::FieldSignature;
+    pub use crate::signature::TypeSignature;
+    pub use crate::to_input_value::ToInputValue;
+    pub use crate::to_input_value::extend_input_fields;
+    pub use crate::to_input_value::insert_input_field;
     pub use crate::type_ref_builder::TypeRefBuilder;
     pub use crate::types::Enum;
     pub use crate::types::ExpandObject;
+    pub use crate::types::FlattenInputObject;
+    pub use crate::types::FlattenObject;
     pub use crate::types::GetInputTypeRef;
     pub use crate::types::GetOutputTypeRef;
+    pub use crate::types::GraphQLError;
     pub use crate::types::InputObject;
     pub use crate::types::InputTypeName;
     pub use crate::types::Interface;
@@ -67,19 +97,28 @@ pub mod internal {
     pub use crate::types::ParentType;
     pub use crate::types::Register;
     pub use crate::types::RegisterFns;
+    pub use crate::types::RegisterInputObject;
     pub use crate::types::Scalar;
     pub use crate::types::TypeName;
     pub use crate::types::Union;
+    pub use crate::types::UnionMember;
+    pub use crate::types::get_with_output_type_ref;
+    pub use crate::types::register_with_output;
 }
 
 pub mod experimental {
     pub use crate::data::GetSchemaData;
 }
 
+pub use dyn_enum::DynEnum;
+pub use dyn_enum::DynEnumTag;
 pub use dynamic_graphql_derive::App;
 pub use dynamic_graphql_derive::Enum;
+pub use dynamic_graphql_derive::EnumExtension;
 pub use dynamic_graphql_derive::ExpandObject;
 pub use dynamic_graphql_derive::ExpandObjectFields;
+#[doc = include_str!("./docs/graphql-error.md")]
+pub use dynamic_graphql_derive::GraphQLError;
 pub use dynamic_graphql_derive::InputObject;
 #[doc = include_str!("./docs/interface.md")]
 pub use dynamic_graphql_derive::Interface;
@@ -94,6 +133,12 @@ pub use dynamic_graphql_derive::ResolvedObjectFields;
 pub use dynamic_graphql_derive::Scalar;
 #[doc = include_str!("./docs/simple-object.md")]
 pub use dynamic_graphql_derive::SimpleObject;
+#[doc = include_str!("./docs/transparent.md")]
+pub use dynamic_graphql_derive::Transparent;
 pub use dynamic_graphql_derive::Union;
+pub use errors::ErrorCode;
+pub use errors::ErrorCodes;
+pub use instance::AnyObject;
 pub use instance::Instance;
 pub use types::ScalarValue;
+pub use union_value::UnionValue;
@@ -6,7 +6,15 @@ use std::mem;
 
 use crate::data::SchemaData;
 use crate::dynamic;
+use crate::errors;
+use crate::errors::ErrorCode;
+use crate::resolve::ListItemErrors;
+use crate::signature;
+use crate::signature::TypeSignature;
+use crate::types::GraphQLError;
+use crate::types::Object;
 use crate::types::Register;
+use crate::types::Union;
 
 pub struct Registry {
     pub data: SchemaData,
@@ -14,10 +22,19 @@ pub struct Registry {
     mutation: Option<String>,
     subscription: Option<String>,
     objects: HashMap<String, dynamic::Object>,
+    unions: HashMap<String, dynamic::Union>,
+    enums: HashMap<String, dynamic::Enum>,
+    // name of all registered interfaces
+    interfaces: HashSet<String>,
     types: Vec<dynamic::Type>,
     // name of all registered types
     names: HashSet<TypeId>,
+    // name of all registered `errors_as_union` unions
+    errors_unions: HashSet<String>,
+    signatures: HashMap<String, TypeSignature>,
     pending_expand_objects: VecDeque<PendingExpandObject>,
+    union_members: Vec<UnionMemberDef>,
+    pending_expand_enums: Vec<PendingExpandEnum>,
 }
 
 impl Default for Registry {
@@ -34,9 +51,16 @@ impl Registry {
             mutation: None,
             subscription: None,
             objects: Default::default(),
+            unions: Default::default(),
+            enums: Default::default(),
+            interfaces: Default::default(),
             types: Default::default(),
             names: Default::default(),
+            errors_unions: Default::default(),
+            signatures: Default::default(),
             pending_expand_objects: Default::default(),
+            union_members: Default::default(),
+            pending_expand_enums: Default::default(),
         }
     }
 }
@@ -47,6 +71,17 @@ struct PendingExpandObject {
     map_fn: Box<dyn FnOnce(dynamic::Object) -> dynamic::Object>,
 }
 
+struct PendingExpandEnum {
+    target: String,
+    expansion: String,
+    map_fn: Box<dyn FnOnce(dynamic::Enum) -> dynamic::Enum>,
+}
+
+struct UnionMemberDef {
+    union: String,
+    member: String,
+}
+
 impl Registry {
     #[inline]
     pub fn set_root(mut self, name: &str) -> Self {
@@ -69,6 +104,16 @@ impl Registry {
             dynamic::Type::Object(object) => {
                 self.objects.insert(object.type_name().to_string(), object);
             }
+            dynamic::Type::Union(union) => {
+                self.unions.insert(union.type_name().to_string(), union);
+            }
+            dynamic::Type::Enum(enm) => {
+                self.enums.insert(enm.type_name().to_string(), enm);
+            }
+            dynamic::Type::Interface(interface) => {
+                self.interfaces.insert(interface.type_name().to_string());
+                self.types.push(dynamic::Type::Interface(interface));
+            }
             _ => {
                 self.types.push(ty);
             }
@@ -86,6 +131,87 @@ impl Registry {
         });
         self
     }
+    /// Update the enum `target` when the schema is created, e.g. to add the values of a module
+    /// to the enum of a [`DynEnum`](crate::DynEnum).
+    pub fn update_enum<F>(mut self, target: &str, expansion_name: &str, f: F) -> Self
+    where
+        F: FnOnce(dynamic::Enum) -> dynamic::Enum + 'static,
+    {
+        self.pending_expand_enums.push(PendingExpandEnum {
+            target: target.to_string(),
+            expansion: expansion_name.to_string(),
+            map_fn: Box::new(f),
+        });
+        self
+    }
+    /// Add a member to the union `target` when the schema is created, the union can be
+    /// defined in another crate.
+    ///
+    /// The member can be an object, a union, whose members are added, or an interface, whose
+    /// registered implementors are added.
+    pub fn update_union(mut self, target: &str, member: &str) -> Self {
+        self.union_members.push(UnionMemberDef {
+            union: target.to_string(),
+            member: member.to_string(),
+        });
+        self
+    }
+}
+
+impl Registry {
+    /// Record the shape of a type, used to check the interface implementations
+    pub fn add_signature(mut self, signature: TypeSignature) -> Self {
+        match self.signatures.get_mut(signature.name()) {
+            Some(existing) => existing.merge(signature),
+            None => {
+                self.signatures
+                    .insert(signature.name().to_string(), signature);
+            }
+        }
+        self
+    }
+
+    /// Check that the types marked with `#[graphql(mark(Trait))]` match the fields of the interface
+    pub fn check_interfaces(&self) -> Result<(), Vec<String>> {
+        let errors = signature::check_interface_marks(&self.signatures);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl Registry {
+    pub fn register_error<E: GraphQLError>(mut self) -> Self {
+        errors::register_error::<E>(&mut self.data);
+        self
+    }
+
+    /// All the error codes registered by `#[derive(GraphQLError)]` types
+    pub fn error_codes(&self) -> &[ErrorCode] {
+        errors::get_error_codes(&self.data)
+    }
+}
+
+impl Registry {
+    /// Register the result union of an `#[graphql(errors_as_union)]` field.
+    ///
+    /// The union contains the `T` object and all the members of the `E` union.
+    pub fn register_errors_union<T, E>(self, name: &str) -> Self
+    where
+        T: Object + Register + 'static,
+        E: Union + Register + 'static,
+    {
+        let mut registry = self.register::<T>().register::<E>();
+        if !registry.errors_unions.insert(name.to_string()) {
+            return registry;
+        }
+        registry
+            .update_union(name, T::get_object_type_name().as_ref())
+            .update_union(name, E::get_union_type_name().as_ref())
+            .register_type(dynamic::Union::new(name))
+    }
 }
 
 impl Registry {
@@ -129,6 +255,79 @@ impl Registry {
             }
         }
     }
+    fn apply_pending_enums(&mut self) {
+        let mut missing = Vec::new();
+        for pending in mem::take(&mut self.pending_expand_enums) {
+            match self.enums.remove(&pending.target) {
+                Some(enm) => {
+                    self.enums.insert(pending.target, (pending.map_fn)(enm));
+                }
+                None => missing.push(format!(
+                    "{} when defining {}",
+                    pending.target, pending.expansion
+                )),
+            }
+        }
+        if !missing.is_empty() {
+            panic!("Can't find enum: {:?}", missing.join(", "));
+        }
+    }
+
+    /// the objects of the union member `member`, the members of the unions in `visited` are skipped
+    fn get_union_member_types(&self, member: &str, visited: &mut Vec<String>) -> Vec<String> {
+        if self.unions.contains_key(member) {
+            if visited.iter().any(|name| name == member) {
+                return Vec::new();
+            }
+            visited.push(member.to_string());
+            return self
+                .union_members
+                .iter()
+                .filter(|def| def.union == member)
+                .flat_map(|def| self.get_union_member_types(&def.member, visited))
+                .collect();
+        }
+        if self.interfaces.contains(member) {
+            let mut implementors = self
+                .objects
+                .keys()
+                .filter(|name| signature::get_interfaces(&self.signatures, name).contains(&member))
+                .cloned()
+                .collect::<Vec<_>>();
+            implementors.sort();
+            return implementors;
+        }
+        vec![member.to_string()]
+    }
+
+    fn apply_union_members(&mut self) {
+        let mut names = self.unions.keys().cloned().collect::<Vec<_>>();
+        names.sort();
+        for name in names {
+            let mut types: Vec<String> = Vec::new();
+            for ty in self.get_union_member_types(&name, &mut Vec::new()) {
+                if !types.contains(&ty) {
+                    types.push(ty);
+                }
+            }
+            let signature = types
+                .iter()
+                .fold(TypeSignature::new(&name), |signature, ty| {
+                    signature.possible_type(ty)
+                });
+            self.signatures
+                .entry(name.clone())
+                .or_insert_with(|| TypeSignature::new(&name))
+                .merge(signature);
+            if let Some(union) = self.unions.remove(&name) {
+                let union = types
+                    .iter()
+                    .fold(union, |union, ty| union.possible_type(ty));
+                self.unions.insert(name, union);
+            }
+        }
+    }
+
     pub fn create_schema(self) -> dynamic::SchemaBuilder {
         let Some(ref root) = self.root else {
             panic!("No root object defined");
@@ -138,19 +337,42 @@ impl Registry {
         self.apply_into_schema_builder(schema)
     }
 
+    /// Add the registered types to the schema.
+    ///
+    /// Panics with all the mismatches reported by [`Registry::check_interfaces`], if any.
     pub fn apply_into_schema_builder(
         mut self,
         schema: dynamic::SchemaBuilder,
     ) -> dynamic::SchemaBuilder {
+        self.apply_union_members();
+        if let Err(errors) = self.check_interfaces() {
+            panic!("{}", errors.join("\n"));
+        }
         self.apply_pending_objects();
+        self.apply_pending_enums();
+        let signatures = &self.signatures;
         let schema = self
             .objects
             .into_iter()
-            .fold(schema, |schema, (_, object)| schema.register(object));
+            .fold(schema, |schema, (name, object)| {
+                // objects implement the interfaces extended by their interfaces too
+                let object = signature::get_missing_interfaces(signatures, &name)
+                    .into_iter()
+                    .fold(object, |object, interface| object.implement(interface));
+                schema.register(object)
+            });
+        let schema = self
+            .enums
+            .into_values()
+            .fold(schema, |schema, enm| schema.register(enm));
+        let schema = self
+            .unions
+            .into_values()
+            .fold(schema, |schema, union| schema.register(union));
         let schema = self
             .types
             .into_iter()
             .fold(schema, |schema, object| schema.register(object));
-        schema.data(self.data)
+        schema.extension(ListItemErrors).data(self.data)
     }
 }
@@ -1,4 +1,17 @@
 use std::borrow::Cow;
+use std::sync::Arc;
+
+use async_graphql::QueryPathSegment;
+use async_graphql::ServerError;
+use async_graphql::ServerResult;
+use async_graphql::Value;
+use async_graphql::extensions::Extension;
+use async_graphql::extensions::ExtensionContext;
+use async_graphql::extensions::ExtensionFactory;
+use async_graphql::extensions::NextResolve;
+use async_graphql::extensions::ResolveInfo;
+use async_graphql::futures_util::Stream;
+use async_graphql::futures_util::StreamExt;
 
 use crate::Context;
 use crate::Error;
@@ -18,6 +31,139 @@ pub trait Resolve<'a> {
     fn resolve(self, ctx: &Context) -> Result<Option<FieldValue<'a>>>;
 }
 
+/// Resolve a list in lenient mode, used by `#[graphql(partial_list)]` fields.
+///
+/// Failed items are reported in the response errors and resolved to `null`,
+/// but the failure never propagates to the list itself.
+pub trait ResolvePartialList<'a> {
+    fn resolve_partial_list(self, ctx: &Context) -> Result<Option<FieldValue<'a>>>;
+}
+
+/// The error of a non-null list item.
+///
+/// The wrapped error already has the path of the item. It propagates up to the
+/// closest nullable parent, which resolves to `null` and reports it. When there
+/// is no nullable parent in the field, [`ListItemErrors`] reports it instead of
+/// the field error.
+struct ListItemError(ServerError);
+
+/// Report the errors propagated from non-null list items with the path of the item.
+///
+/// The errors returned by the field resolvers have no path, so the error of the
+/// field is replaced by the error of the item.
+pub(crate) struct ListItemErrors;
+
+impl ExtensionFactory for ListItemErrors {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(ListItemErrors)
+    }
+}
+
+#[async_graphql::async_trait::async_trait]
+impl Extension for ListItemErrors {
+    async fn resolve(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        info: ResolveInfo<'_>,
+        next: NextResolve<'_>,
+    ) -> ServerResult<Option<Value>> {
+        next.run(ctx, info).await.map_err(|err| {
+            match err
+                .source
+                .as_ref()
+                .and_then(|source| source.downcast_ref::<ListItemError>())
+            {
+                Some(ListItemError(item_error)) => item_error.clone(),
+                None => err,
+            }
+        })
+    }
+}
+
+fn get_list_item_error(err: &Error) -> Option<&ListItemError> {
+    err.source.as_ref()?.downcast_ref()
+}
+
+fn into_list_item_error(ctx: &Context, err: Error) -> Error {
+    if get_list_item_error(&err).is_some() {
+        return err;
+    }
+    let server_error = ctx.set_error_path(err.clone().into_server_error(ctx.item.pos));
+    Error {
+        message: err.message,
+        source: Some(Arc::new(ListItemError(server_error))),
+        extensions: err.extensions,
+    }
+}
+
+fn report_error(ctx: &Context, err: Error) {
+    match get_list_item_error(&err) {
+        Some(ListItemError(server_error)) => ctx.add_error(server_error.clone()),
+        None => ctx.add_error(ctx.set_error_path(err.into_server_error(ctx.item.pos))),
+    }
+}
+
+fn is_list_item(ctx: &Context) -> bool {
+    matches!(
+        ctx.path_node.as_ref().map(|node| &node.segment),
+        Some(QueryPathSegment::Index(_))
+    )
+}
+
+/// Resolve to `null` when the error was propagated from a non-null list item,
+/// or when the nullable value is a list item itself.
+fn null_on_list_item_error<'a>(
+    ctx: &Context,
+    value: Result<Option<FieldValue<'a>>>,
+) -> Result<Option<FieldValue<'a>>> {
+    match value {
+        Err(err) if get_list_item_error(&err).is_some() || is_list_item(ctx) => {
+            report_error(ctx, err);
+            Ok(None)
+        }
+        value => value,
+    }
+}
+
+/// Resolve the items of a list.
+///
+/// An item error is reported with the path of the item. Nullable items
+/// resolve to `null`, otherwise the error propagates to the list. In `partial`
+/// mode every failed item resolves to `null`.
+fn resolve_list<'a, T, I>(ctx: &Context, items: I, partial: bool) -> Result<Option<FieldValue<'a>>>
+where
+    I: Iterator<Item = T>,
+    T: Resolve<'a>,
+{
+    let mut values = Vec::new();
+    for (index, item) in items.enumerate() {
+        let ctx_idx = ctx.with_index(index);
+        match item.resolve(&ctx_idx) {
+            Ok(Some(value)) => values.push(value),
+            Ok(None) => values.push(FieldValue::NULL),
+            Err(err) => {
+                if !partial {
+                    return Err(into_list_item_error(&ctx_idx, err));
+                }
+                report_error(&ctx_idx, err);
+                values.push(FieldValue::NULL);
+            }
+        }
+    }
+    Ok(Some(FieldValue::list(values)))
+}
+
+/// Resolve the items of an iterator as a list.
+///
+/// Used for fields returning `impl Iterator<Item = T>`, the items are resolved
+/// without being collected into a `Vec` first.
+pub struct ResolveIter<I>(pub I);
+
+/// Collect the items of a stream, used for fields returning `impl Stream<Item = T>`.
+pub async fn collect_stream<S: Stream>(stream: S) -> Vec<S::Item> {
+    stream.collect().await
+}
+
 mod resolve_ref {
     use super::*;
     // &Option<T>
@@ -29,7 +175,7 @@ mod resolve_ref {
         fn resolve_ref(&'a self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
             match self {
                 None => Ok(None),
-                Some(value) => value.resolve(ctx),
+                Some(value) => null_on_list_item_error(ctx, value.resolve(ctx)),
             }
         }
     }
@@ -40,15 +186,7 @@ mod resolve_ref {
         &'a T: Resolve<'a> + 'a,
     {
         fn resolve_ref(&'a self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
-            let iter = self.iter();
-            let items = iter.enumerate().map(|(index, item)| {
-                let ctx_idx = ctx.with_index(index);
-                match item.resolve(&ctx_idx) {
-                    Ok(Some(value)) => value,
-                    _ => FieldValue::NULL,
-                }
-            });
-            Ok(Some(FieldValue::list(items)))
+            resolve_list(ctx, self.iter(), false)
         }
     }
     // &ID
@@ -101,7 +239,7 @@ mod resolve_own {
         fn resolve_owned(self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
             match self {
                 None => Ok(None),
-                Some(value) => value.resolve(ctx),
+                Some(value) => null_on_list_item_error(ctx, value.resolve(ctx)),
             }
         }
     }
@@ -127,15 +265,7 @@ mod resolve_own {
         T: Resolve<'a>,
     {
         fn resolve_owned(self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
-            let iter = self.into_iter();
-            let items = iter.enumerate().map(|(index, item)| {
-                let ctx_idx = ctx.with_index(index);
-                match item.resolve(&ctx_idx) {
-                    Ok(Some(value)) => value,
-                    _ => FieldValue::NULL,
-                }
-            });
-            Ok(Some(FieldValue::list(items)))
+            resolve_list(ctx, self.into_iter(), false)
         }
     }
 
@@ -145,15 +275,18 @@ mod resolve_own {
         &'a T: Resolve<'a>,
     {
         fn resolve_owned(self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
-            let iter = self.iter();
-            let items = iter.enumerate().map(|(index, item)| {
-                let ctx_idx = ctx.with_index(index);
-                match item.resolve(&ctx_idx) {
-                    Ok(Some(value)) => value,
-                    _ => FieldValue::NULL,
-                }
-            });
-            Ok(Some(FieldValue::list(items)))
+            resolve_list(ctx, self.iter(), false)
+        }
+    }
+
+    // impl Iterator<Item = T>
+    impl<'a, I> ResolveOwned<'a> for ResolveIter<I>
+    where
+        I: Iterator,
+        I::Item: Resolve<'a>,
+    {
+        fn resolve_owned(self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+            resolve_list(ctx, self.0, false)
         }
     }
 
@@ -174,6 +307,93 @@ mod resolve_own {
     }
 }
 
+mod resolve_partial_list {
+    use super::*;
+    // Vec<T>
+    impl<'a, T> ResolvePartialList<'a> for Vec<T>
+    where
+        T: Resolve<'a>,
+    {
+        fn resolve_partial_list(self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+            resolve_list(ctx, self.into_iter(), true)
+        }
+    }
+
+    // &Vec<T>
+    impl<'a, T> ResolvePartialList<'a> for &'a Vec<T>
+    where
+        &'a T: Resolve<'a>,
+    {
+        fn resolve_partial_list(self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+            resolve_list(ctx, self.iter(), true)
+        }
+    }
+
+    // &[T]
+    impl<'a, T> ResolvePartialList<'a> for &'a [T]
+    where
+        &'a T: Resolve<'a>,
+    {
+        fn resolve_partial_list(self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+            resolve_list(ctx, self.iter(), true)
+        }
+    }
+
+    // impl Iterator<Item = T>
+    impl<'a, I> ResolvePartialList<'a> for ResolveIter<I>
+    where
+        I: Iterator,
+        I::Item: Resolve<'a>,
+    {
+        fn resolve_partial_list(self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+            resolve_list(ctx, self.0, true)
+        }
+    }
+
+    // Option<T>
+    impl<'a, T> ResolvePartialList<'a> for Option<T>
+    where
+        T: ResolvePartialList<'a>,
+    {
+        #[inline]
+        fn resolve_partial_list(self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+            match self {
+                None => Ok(None),
+                Some(value) => value.resolve_partial_list(ctx),
+            }
+        }
+    }
+
+    // &Option<T>
+    impl<'a, T> ResolvePartialList<'a> for &'a Option<T>
+    where
+        &'a T: ResolvePartialList<'a>,
+    {
+        #[inline]
+        fn resolve_partial_list(self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+            match self {
+                None => Ok(None),
+                Some(value) => value.resolve_partial_list(ctx),
+            }
+        }
+    }
+
+    // Result<T, E>
+    impl<'a, T, E> ResolvePartialList<'a> for Result<T, E>
+    where
+        T: ResolvePartialList<'a>,
+        E: Into<Error>,
+    {
+        #[inline]
+        fn resolve_partial_list(self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+            match self {
+                Ok(value) => value.resolve_partial_list(ctx),
+                Err(err) => Err(err.into()),
+            }
+        }
+    }
+}
+
 // T
 impl<'a, T: ResolveOwned<'a>> Resolve<'a> for T {
     #[inline]
@@ -0,0 +1,338 @@
+use std::collections::HashMap;
+
+use crate::dynamic::TypeRef;
+
+/// The shape of a field, recorded by the derive macros to check interface implementations
+#[derive(Debug, Clone)]
+pub struct FieldSignature {
+    name: String,
+    ty: TypeRef,
+    arguments: Vec<(String, TypeRef)>,
+}
+
+impl FieldSignature {
+    pub fn new(name: impl Into<String>, ty: impl Into<TypeRef>) -> Self {
+        Self {
+            name: name.into(),
+            ty: ty.into(),
+            arguments: Vec::new(),
+        }
+    }
+
+    pub fn argument(mut self, name: impl Into<String>, ty: impl Into<TypeRef>) -> Self {
+        self.arguments.push((name.into(), ty.into()));
+        self
+    }
+
+    fn get_argument(&self, name: &str) -> Option<&TypeRef> {
+        self.arguments
+            .iter()
+            .find(|(arg_name, _)| arg_name == name)
+            .map(|(_, ty)| ty)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct InterfaceMarkSignature {
+    interface: String,
+    rust_trait: &'static str,
+}
+
+/// The shape of an output type, recorded by the derive macros to check interface implementations
+///
+/// Signatures with the same name are merged, so the fields of an object can be recorded by
+/// more than one macro (e.g. `ResolvedObject` and `ExpandObjectFields`).
+#[derive(Debug, Clone)]
+pub struct TypeSignature {
+    name: String,
+    rust_type: Option<&'static str>,
+    fields: Vec<FieldSignature>,
+    marks: Vec<InterfaceMarkSignature>,
+    implements: Vec<String>,
+    possible_types: Vec<String>,
+}
+
+impl TypeSignature {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            rust_type: None,
+            fields: Vec::new(),
+            marks: Vec::new(),
+            implements: Vec::new(),
+            possible_types: Vec::new(),
+        }
+    }
+
+    pub fn rust_type(mut self, rust_type: &'static str) -> Self {
+        self.rust_type = Some(rust_type);
+        self
+    }
+
+    pub fn field(mut self, field: FieldSignature) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    /// The type implements the interface with `#[graphql(mark(Trait))]`, its fields are checked
+    pub fn mark(mut self, interface: impl Into<String>, rust_trait: &'static str) -> Self {
+        let interface = interface.into();
+        self.implements.push(interface.clone());
+        self.marks.push(InterfaceMarkSignature {
+            interface,
+            rust_trait,
+        });
+        self
+    }
+
+    /// The type implements the interface with the fields of the interface
+    pub fn implement(mut self, interface: impl Into<String>) -> Self {
+        self.implements.push(interface.into());
+        self
+    }
+
+    pub fn possible_type(mut self, name: impl Into<String>) -> Self {
+        self.possible_types.push(name.into());
+        self
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn merge(&mut self, other: TypeSignature) {
+        if other.rust_type.is_some() {
+            self.rust_type = other.rust_type;
+        }
+        self.fields.extend(other.fields);
+        self.marks.extend(other.marks);
+        self.implements.extend(other.implements);
+        self.possible_types.extend(other.possible_types);
+    }
+
+    fn get_field(&self, name: &str) -> Option<&FieldSignature> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    fn describe(&self) -> String {
+        match self.rust_type {
+            Some(rust_type) => format!("\"{}\" (`{}`)", self.name, rust_type),
+            None => format!("\"{}\"", self.name),
+        }
+    }
+}
+
+/// the interfaces implemented by the type `name`, including the interfaces they extend
+pub(crate) fn get_interfaces<'a>(
+    signatures: &'a HashMap<String, TypeSignature>,
+    name: &str,
+) -> Vec<&'a str> {
+    let mut interfaces: Vec<&str> = Vec::new();
+    let mut pending = vec![name];
+    while let Some(name) = pending.pop() {
+        let Some(signature) = signatures.get(name) else {
+            continue;
+        };
+        for interface in &signature.implements {
+            if !interfaces.contains(&interface.as_str()) {
+                interfaces.push(interface);
+                pending.push(interface);
+            }
+        }
+    }
+    interfaces
+}
+
+/// the interfaces extended by the implemented interfaces of the type `name`, that the type
+/// does not implement itself
+pub(crate) fn get_missing_interfaces<'a>(
+    signatures: &'a HashMap<String, TypeSignature>,
+    name: &str,
+) -> Vec<&'a str> {
+    let Some(signature) = signatures.get(name) else {
+        return Vec::new();
+    };
+    get_interfaces(signatures, name)
+        .into_iter()
+        .filter(|interface| !signature.implements.iter().any(|name| name == interface))
+        .collect()
+}
+
+/// the fields of the interface, including the fields of the interfaces it extends
+fn get_interface_fields<'a>(
+    signatures: &'a HashMap<String, TypeSignature>,
+    interface: &'a TypeSignature,
+) -> Vec<&'a FieldSignature> {
+    let parents = get_interfaces(signatures, &interface.name)
+        .into_iter()
+        .filter_map(|name| signatures.get(name));
+    let mut fields: Vec<&FieldSignature> = Vec::new();
+    for field in std::iter::once(interface)
+        .chain(parents)
+        .flat_map(|sig| &sig.fields)
+    {
+        if !fields.iter().any(|existing| existing.name == field.name) {
+            fields.push(field);
+        }
+    }
+    fields
+}
+
+fn is_named_subtype(signatures: &HashMap<String, TypeSignature>, ty: &str, target: &str) -> bool {
+    if ty == target {
+        return true;
+    }
+    let implements = get_interfaces(signatures, ty).contains(&target);
+    let is_member = signatures
+        .get(target)
+        .is_some_and(|signature| signature.possible_types.iter().any(|name| name == ty));
+    implements || is_member
+}
+
+/// check if the output type `ty` can be used where `target` is expected (covariance)
+fn is_subtype(signatures: &HashMap<String, TypeSignature>, ty: &TypeRef, target: &TypeRef) -> bool {
+    match (ty, target) {
+        (TypeRef::NonNull(ty), TypeRef::NonNull(target)) => is_subtype(signatures, ty, target),
+        (TypeRef::NonNull(ty), target) => is_subtype(signatures, ty, target),
+        (TypeRef::List(ty), TypeRef::List(target)) => is_subtype(signatures, ty, target),
+        (TypeRef::Named(ty), TypeRef::Named(target)) => is_named_subtype(signatures, ty, target),
+        _ => false,
+    }
+}
+
+fn check_field(
+    signatures: &HashMap<String, TypeSignature>,
+    field: &FieldSignature,
+    interface_field: &FieldSignature,
+    errors: &mut Vec<String>,
+) {
+    if !is_subtype(signatures, &field.ty, &interface_field.ty) {
+        errors.push(format!(
+            "field \"{}\" returns \"{}\", expected \"{}\" or a subtype",
+            field.name, field.ty, interface_field.ty
+        ));
+    }
+    for (name, ty) in &interface_field.arguments {
+        match field.get_argument(name) {
+            None => errors.push(format!(
+                "field \"{}\" is missing argument \"{}: {}\"",
+                field.name, name, ty
+            )),
+            Some(arg_ty) if arg_ty != ty => errors.push(format!(
+                "argument \"{}.{}\" accepts \"{}\", expected \"{}\"",
+                field.name, name, arg_ty, ty
+            )),
+            Some(_) => {}
+        }
+    }
+    for (name, ty) in &field.arguments {
+        if matches!(ty, TypeRef::NonNull(_)) && interface_field.get_argument(name).is_none() {
+            errors.push(format!(
+                "field \"{}\" has additional required argument \"{}: {}\"",
+                field.name, name, ty
+            ));
+        }
+    }
+}
+
+/// check that the types marked with `#[graphql(mark(Trait))]` have the fields of the interfaces
+pub(crate) fn check_interface_marks(signatures: &HashMap<String, TypeSignature>) -> Vec<String> {
+    let mut names = signatures.keys().collect::<Vec<_>>();
+    names.sort();
+
+    let mut errors = Vec::new();
+    for signature in names.into_iter().map(|name| &signatures[name]) {
+        for mark in &signature.marks {
+            let Some(interface) = signatures.get(&mark.interface) else {
+                continue;
+            };
+            let mut problems = Vec::new();
+            for interface_field in get_interface_fields(signatures, interface) {
+                match signature.get_field(&interface_field.name) {
+                    Some(field) => check_field(signatures, field, interface_field, &mut problems),
+                    None => problems.push(format!(
+                        "missing field \"{}: {}\"",
+                        interface_field.name, interface_field.ty
+                    )),
+                }
+            }
+            errors.extend(problems.into_iter().map(|problem| {
+                format!(
+                    "{} marked with `{}` does not implement interface {}: {}",
+                    signature.describe(),
+                    mark.rust_trait,
+                    interface.describe(),
+                    problem
+                )
+            }));
+        }
+    }
+    errors
+}
+
+/// The names of the fields defined by the derive of an object or of an input object, with the
+/// names of its flattened types, known at compile time
+#[derive(Debug, Clone, Copy)]
+pub struct FieldNames {
+    pub names: &'static [&'static str],
+    pub flattened: &'static [FieldNames],
+}
+
+impl FieldNames {
+    pub const EMPTY: Self = Self {
+        names: &[],
+        flattened: &[],
+    };
+
+    pub const fn contains(&self, name: &str) -> bool {
+        let mut index = 0;
+        while index < self.names.len() {
+            if str_eq(self.names[index], name) {
+                return true;
+            }
+            index += 1;
+        }
+        let mut index = 0;
+        while index < self.flattened.len() {
+            if self.flattened[index].contains(name) {
+                return true;
+            }
+            index += 1;
+        }
+        false
+    }
+
+    /// whether a name is in both, e.g. in two flattened objects
+    pub const fn intersects(&self, other: &FieldNames) -> bool {
+        let mut index = 0;
+        while index < self.names.len() {
+            if other.contains(self.names[index]) {
+                return true;
+            }
+            index += 1;
+        }
+        let mut index = 0;
+        while index < self.flattened.len() {
+            if self.flattened[index].intersects(other) {
+                return true;
+            }
+            index += 1;
+        }
+        false
+    }
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut index = 0;
+    while index < a.len() {
+        if a[index] != b[index] {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
@@ -0,0 +1,94 @@
+use async_graphql::indexmap::IndexMap;
+
+use crate::MaybeUndefined;
+use crate::Name;
+use crate::Value;
+
+/// Converts a value of an input type to a GraphQL value, the reverse of `FromValue`
+///
+/// Parsing the returned value with `FromValue` gives back the same value.
+pub trait ToInputValue {
+    fn to_input_value(&self) -> Value;
+
+    /// the value of the input field, `None` omits the field from the input object
+    fn to_input_field_value(&self) -> Option<Value> {
+        Some(self.to_input_value())
+    }
+}
+
+impl ToInputValue for String {
+    fn to_input_value(&self) -> Value {
+        Value::String(self.clone())
+    }
+}
+
+impl ToInputValue for async_graphql::ID {
+    fn to_input_value(&self) -> Value {
+        Value::String(self.0.clone())
+    }
+}
+
+macro_rules! primitive_to_input_value {
+    ($($t:ty),*) => {
+        $(
+            impl ToInputValue for $t {
+                fn to_input_value(&self) -> Value {
+                    Value::from(*self)
+                }
+            }
+        )*
+    };
+}
+
+primitive_to_input_value!(bool, f32, f64);
+primitive_to_input_value!(u8, u16, u32, u64, usize);
+primitive_to_input_value!(i8, i16, i32, i64, isize);
+
+impl<T: ToInputValue> ToInputValue for Option<T> {
+    fn to_input_value(&self) -> Value {
+        match self {
+            Some(value) => value.to_input_value(),
+            None => Value::Null,
+        }
+    }
+}
+
+impl<T: ToInputValue> ToInputValue for MaybeUndefined<T> {
+    fn to_input_value(&self) -> Value {
+        match self {
+            MaybeUndefined::Value(value) => value.to_input_value(),
+            MaybeUndefined::Null | MaybeUndefined::Undefined => Value::Null,
+        }
+    }
+
+    fn to_input_field_value(&self) -> Option<Value> {
+        match self {
+            MaybeUndefined::Undefined => None,
+            _ => Some(self.to_input_value()),
+        }
+    }
+}
+
+impl<T: ToInputValue> ToInputValue for Vec<T> {
+    fn to_input_value(&self) -> Value {
+        Value::List(self.iter().map(ToInputValue::to_input_value).collect())
+    }
+}
+
+/// adds a field to the input object value of a derived input type
+pub fn insert_input_field(
+    fields: &mut IndexMap<Name, Value>,
+    name: &str,
+    value: &impl ToInputValue,
+) {
+    if let Some(value) = value.to_input_field_value() {
+        fields.insert(Name::new(name), value);
+    }
+}
+
+/// adds the fields of a `#[graphql(flatten)]` field to the input object value of a derived input type
+pub fn extend_input_fields(fields: &mut IndexMap<Name, Value>, value: &impl ToInputValue) {
+    if let Value::Object(nested) = value.to_input_value() {
+        fields.extend(nested);
+    }
+}
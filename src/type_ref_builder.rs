@@ -31,6 +31,21 @@ impl TypeRefBuilder {
             TypeRefBuilder::NNListNN(name) => TypeRefBuilder::NNListNN(name),
         }
     }
+
+    pub fn nullable_items(self) -> Self {
+        match self {
+            TypeRefBuilder::NNList(name) => TypeRefBuilder::List(name),
+            TypeRefBuilder::NNListNN(name) => TypeRefBuilder::ListNN(name),
+            other => other,
+        }
+    }
+
+    pub fn is_nullable(&self) -> bool {
+        matches!(
+            self,
+            TypeRefBuilder::Named(_) | TypeRefBuilder::List(_) | TypeRefBuilder::NNList(_)
+        )
+    }
 }
 
 impl From<TypeRefBuilder> for dynamic::TypeRef {
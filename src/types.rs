@@ -1,6 +1,13 @@
 use std::borrow::Cow;
 
+use async_graphql::ErrorExtensionValues;
+use async_graphql::dynamic;
+
+use crate::errors::ErrorCode;
+use crate::errors::InputValueResult;
 use crate::registry::Registry;
+use crate::signature::FieldNames;
+use crate::signature::TypeSignature;
 use crate::type_ref_builder::TypeRefBuilder;
 
 mod common;
@@ -14,6 +21,9 @@ pub trait Register {
 
 pub trait RegisterFns {
     const REGISTER_FNS: &'static [fn(registry: Registry) -> Registry];
+    /// names of the fields defined by the derive of the type, the `ResolvedObjectFields` of the
+    /// type can't define them again
+    const FIELD_NAMES: FieldNames = FieldNames::EMPTY;
 }
 
 pub trait TypeName: Register {
@@ -33,11 +43,32 @@ pub trait InputTypeName: TypeName {
 }
 
 pub trait Object: OutputTypeName + ParentType {
+    /// the type is marked with `#[graphql(mark(Trait))]`, the fields of the type and of its
+    /// expansions are recorded to check the interfaces
+    const MARKED: bool = false;
+
     fn get_object_type_name() -> Cow<'static, str> {
         <Self as OutputTypeName>::get_output_type_name()
     }
 }
 
+/// An object whose fields can be added to another object with `#[graphql(flatten)]`
+pub trait FlattenObject: Object + Sized {
+    /// register the types of the fields
+    fn register_fields(registry: Registry) -> Registry;
+    /// Define the fields on the object of the parent type `P`.
+    ///
+    /// `get` returns the value of the object from the value of the parent.
+    fn define_fields<P, G>(object: dynamic::Object, get: G) -> dynamic::Object
+    where
+        P: 'static,
+        G: Fn(&P) -> &Self + Copy + Send + Sync + 'static;
+    /// record the shape of the fields, see `Registry::add_signature`
+    fn fields_signature(signature: TypeSignature) -> TypeSignature;
+    /// names of the fields, including the fields of the flattened objects
+    const FIELD_NAMES: FieldNames;
+}
+
 pub trait Enum: OutputTypeName {
     fn get_enum_type_name() -> Cow<'static, str> {
         <Self as OutputTypeName>::get_output_type_name()
@@ -63,10 +94,44 @@ pub trait Union: OutputTypeName {
     }
 }
 
+/// A type that can be a variant of a union: an object, a union or an interface instance
+pub trait UnionMember: OutputTypeName {
+    /// names of the objects that the member adds to the union
+    ///
+    /// the implementors of the interface members are added when the schema is created
+    fn get_union_member_types() -> Vec<Cow<'static, str>>;
+    /// Set the object type of the resolved value of the member
+    ///
+    /// unions and interface instances already resolve to a value with the type of the object
+    fn with_union_member_type(value: dynamic::FieldValue<'_>) -> dynamic::FieldValue<'_> {
+        value
+    }
+}
+
+impl<T: Object> UnionMember for T {
+    fn get_union_member_types() -> Vec<Cow<'static, str>> {
+        vec![T::get_object_type_name()]
+    }
+    fn with_union_member_type(value: dynamic::FieldValue<'_>) -> dynamic::FieldValue<'_> {
+        value.with_type(T::get_object_type_name())
+    }
+}
+
 pub trait Interface: OutputTypeName {
     fn get_interface_type_name() -> Cow<'static, str> {
         <Self as OutputTypeName>::get_output_type_name()
     }
+    /// Define the fields of the interface and of the interfaces it extends on `object`.
+    ///
+    /// The names of the visited interfaces are pushed to `interfaces`, an interface that is
+    /// already in the list is skipped.
+    fn define_interface_fields(
+        object: dynamic::Interface,
+        interfaces: &mut Vec<Cow<'static, str>>,
+    ) -> dynamic::Interface {
+        interfaces.push(Self::get_interface_type_name());
+        object
+    }
 }
 
 pub trait ParentType {
@@ -81,8 +146,33 @@ pub trait InputObject: InputTypeName {
     }
 }
 
+/// An input object whose fields can be added to another input object with `#[graphql(flatten)]`
+pub trait FlattenInputObject: InputObject + Sized {
+    /// register the types of the fields
+    fn register_fields(registry: Registry) -> Registry;
+    /// define the fields on the input object of the parent type
+    fn define_fields(object: dynamic::InputObject) -> dynamic::InputObject;
+    /// parse the value from the fields of the parent input object
+    fn from_fields(object: &dynamic::ObjectAccessor<'_>) -> InputValueResult<Self>;
+    /// names of the fields, including the fields of the flattened input objects
+    const FIELD_NAMES: FieldNames;
+}
+
+/// The input object of a type that is also an object, `#[graphql(input_name = "...")]`
+///
+/// `Register` is implemented by the object, it registers the input object with this trait.
+pub trait RegisterInputObject: InputObject {
+    fn register_input_object(registry: Registry) -> Registry;
+}
+
 pub trait Mutation: ExpandObject {}
 
+pub trait GraphQLError: Register {
+    const ERROR_CODES: &'static [ErrorCode];
+    fn message(&self) -> String;
+    fn error_extensions(&self) -> ErrorExtensionValues;
+}
+
 pub trait ExpandObject: ParentType {
     fn get_expand_object_name() -> Cow<'static, str>;
 }
@@ -91,6 +181,23 @@ pub trait GetOutputTypeRef {
     fn get_output_type_ref() -> TypeRefBuilder;
 }
 
+/// The type of a field mapped with `#[graphql(with = "...")]` is the return type of the function
+pub fn get_with_output_type_ref<I: ?Sized, O: GetOutputTypeRef>(
+    _with: fn(&I) -> O,
+) -> TypeRefBuilder {
+    O::get_output_type_ref()
+}
+
+/// Registers the return type of the function of `#[graphql(with = "...")]`
+pub fn register_with_output<I: ?Sized, O: Register + 'static>(
+    registry: Registry,
+    _with: fn(&I) -> O,
+) -> Registry {
+    registry.register::<O>()
+}
+
 pub trait GetInputTypeRef {
     fn get_input_type_ref() -> TypeRefBuilder;
+    /// whether the input type is nullable, known at compile time to check the deprecated inputs
+    const NULLABLE: bool = false;
 }
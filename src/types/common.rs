@@ -227,6 +227,8 @@ impl<T: InputTypeName> GetInputTypeRef for T {
 }
 
 impl<T: GetInputTypeRef, E> GetInputTypeRef for Result<T, E> {
+    const NULLABLE: bool = true;
+
     #[inline]
     fn get_input_type_ref() -> TypeRefBuilder {
         T::get_input_type_ref().optional()
@@ -234,6 +236,8 @@ impl<T: GetInputTypeRef, E> GetInputTypeRef for Result<T, E> {
 }
 
 impl<T: GetInputTypeRef> GetInputTypeRef for Option<T> {
+    const NULLABLE: bool = true;
+
     #[inline]
     fn get_input_type_ref() -> TypeRefBuilder {
         T::get_input_type_ref().optional()
@@ -241,6 +245,8 @@ impl<T: GetInputTypeRef> GetInputTypeRef for Option<T> {
 }
 
 impl<T: GetInputTypeRef> GetInputTypeRef for MaybeUndefined<T> {
+    const NULLABLE: bool = true;
+
     #[inline]
     fn get_input_type_ref() -> TypeRefBuilder {
         T::get_input_type_ref().optional()
@@ -0,0 +1,139 @@
+use std::any::Any;
+use std::borrow::Cow;
+
+use async_graphql::Context;
+use async_graphql::dynamic::FieldValue;
+
+use crate::any_box::AnyBox;
+use crate::instance::AnyObject;
+use crate::registry::Registry;
+use crate::resolve::ResolveOwned;
+use crate::resolve::ResolveRef;
+use crate::types::Object;
+use crate::types::OutputTypeName;
+use crate::types::Register;
+use crate::types::TypeName;
+use crate::types::Union;
+use crate::types::UnionMember;
+
+/// A value of the union `U` holding any object.
+///
+/// Used to resolve the members added to the union with `#[graphql(extends_union(U))]`,
+/// which are not variants of `U`.
+pub struct UnionValue<'v, U>
+where
+    U: Union,
+{
+    _union: std::marker::PhantomData<U>,
+    value: AnyBox<'v>,
+}
+
+impl<'v, U> UnionValue<'v, U>
+where
+    U: Union,
+{
+    #[inline]
+    pub fn new_owned<T>(value: T) -> Self
+    where
+        T: Object + Send + Sync + 'static,
+    {
+        Self::from_any_box(AnyBox::new_owned(
+            value,
+            <T as Object>::get_object_type_name().to_string(),
+        ))
+    }
+    #[inline]
+    pub fn new_borrowed<T>(value: &'v T) -> Self
+    where
+        T: Object + Send + Sync + 'static,
+    {
+        Self::from_any_box(AnyBox::new_borrowed(
+            value,
+            <T as Object>::get_object_type_name().to_string(),
+        ))
+    }
+    /// Create the value from a boxed object and the name of its object type
+    #[inline]
+    pub fn new_boxed(value: Box<dyn Any + Send + Sync>, type_name: impl Into<String>) -> Self {
+        Self::from_any_box(AnyBox::new_boxed(value, type_name.into()))
+    }
+    fn from_any_box(value: AnyBox<'v>) -> Self {
+        UnionValue {
+            _union: std::marker::PhantomData,
+            value,
+        }
+    }
+    /// The GraphQL name of the object type of the value
+    pub fn type_name(&self) -> &str {
+        self.value.type_name()
+    }
+    pub fn is<T: Any>(&self) -> bool {
+        self.value.is::<T>()
+    }
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.value.downcast_ref()
+    }
+    /// Take the value out, borrowed values are returned as `Err`
+    pub fn downcast<T: Any>(self) -> Result<T, Self> {
+        self.value.downcast().map_err(Self::from_any_box)
+    }
+}
+
+impl<U> From<Box<dyn AnyObject>> for UnionValue<'_, U>
+where
+    U: Union,
+{
+    fn from(value: Box<dyn AnyObject>) -> Self {
+        let name = value.object_type_name().to_string();
+        Self::new_boxed(value.into_any(), name)
+    }
+}
+
+impl<'a, U> ResolveOwned<'a> for UnionValue<'a, U>
+where
+    U: Union,
+{
+    fn resolve_owned(self, ctx: &Context) -> async_graphql::Result<Option<FieldValue<'a>>> {
+        self.value.resolve_owned(ctx)
+    }
+}
+
+impl<'a, U> ResolveRef<'a> for UnionValue<'_, U>
+where
+    U: Union,
+{
+    fn resolve_ref(&'a self, ctx: &Context) -> async_graphql::Result<Option<FieldValue<'a>>> {
+        self.value.resolve_ref(ctx)
+    }
+}
+
+impl<U> Register for UnionValue<'_, U>
+where
+    U: Union + 'static,
+{
+    #[inline]
+    fn register(registry: Registry) -> Registry {
+        registry.register::<U>()
+    }
+}
+
+impl<U> TypeName for UnionValue<'_, U>
+where
+    U: Union + 'static,
+{
+    fn get_type_name() -> Cow<'static, str> {
+        <U as Union>::get_union_type_name()
+    }
+}
+
+impl<U> OutputTypeName for UnionValue<'_, U> where U: Union + 'static {}
+
+impl<U> UnionMember for UnionValue<'_, U>
+where
+    U: Union + 'static,
+{
+    /// the objects are added to the union with `Registry::update_union`
+    fn get_union_member_types() -> Vec<Cow<'static, str>> {
+        vec![]
+    }
+}
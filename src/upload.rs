@@ -8,6 +8,7 @@ use async_graphql::dynamic::ValueAccessor;
 use crate::errors::InputValueResult;
 use crate::from_value::FromValue;
 use crate::registry::Registry;
+use crate::to_input_value::ToInputValue;
 use crate::types::InputTypeName;
 use crate::types::Register;
 use crate::types::TypeName;
@@ -26,6 +27,13 @@ impl FromValue for Upload {
     }
 }
 
+/// the reference to the file of the request, parsed back to the same upload
+impl ToInputValue for Upload {
+    fn to_input_value(&self) -> async_graphql::Value {
+        async_graphql::Value::String(format!("#__graphql_file__:{}", self.0))
+    }
+}
+
 impl Register for Upload {
     fn register(registry: Registry) -> Registry {
         registry.register_type(Type::Upload)